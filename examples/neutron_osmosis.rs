@@ -19,8 +19,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         .build()?;
 
     // Kill and restart the relayer
-    ctx.stop_relayer();
-    ctx.start_relayer();
+    ctx.stop_relayer()?;
+    ctx.start_relayer()?;
 
     // Wait for the relayer to start up
     thread::sleep(Duration::from_secs(10));
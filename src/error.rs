@@ -1,8 +1,10 @@
+use bech32::Error as Bech32Error;
 use localic_std::errors::LocalError;
 use reqwest::Error as ReqwestError;
 use serde_json::Error as SerdeJsonError;
-use std::io::Error as IoError;
+use std::{error::Error as StdError, io::Error as IoError};
 use thiserror::Error;
+use toml::de::Error as TomlDeError;
 
 /// General error during testing.
 #[derive(Error, Debug)]
@@ -13,8 +15,16 @@ pub enum Error {
     Io(#[from] IoError),
     #[error("serialization failed: `{0}`")]
     Serialization(#[from] SerdeJsonError),
+    #[error("TOML deserialization failed: `{0}`")]
+    TomlDeserialization(#[from] TomlDeError),
+    #[error("invalid bech32 address: `{0}`")]
+    Bech32(#[from] Bech32Error),
     #[error("failed to query container with cmd `{0}`")]
     ContainerCmd(String),
+    #[error("the query `{cmd}` failed: {output:?}")]
+    QueryFailed { cmd: String, output: String },
+    #[error("the value {value:?} of `--{flag}` contains whitespace, which local-ic would split into separate arguments")]
+    InvalidFlagValue { flag: String, value: String },
     #[error("an unknown error occurred: `{0}`")]
     Misc(String),
     #[error("test context missing variable `{0}`")]
@@ -25,6 +35,39 @@ pub enum Error {
     TxFailed { hash: String, error: String },
     #[error("the transaction has no logs")]
     TxMissingLogs,
+    #[error("the transaction {hash:?} emitted no `{kind}` event with attributes {attributes:?}")]
+    EventNotEmitted {
+        hash: String,
+        kind: String,
+        attributes: Vec<String>,
+    },
+    #[error("failed to read the local-ic logs file `{path}`: {source}")]
+    LogFile {
+        path: String,
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+    #[error("timed out waiting for packet {sequence} on {channel} to be acknowledged")]
+    AckTimeout { sequence: u64, channel: String },
+    #[error("timed out waiting for the ccv channel between {provider} and {consumer} to open")]
+    CcvChannelTimeout { provider: String, consumer: String },
+    #[error("packet {sequence} on {channel} timed out before it was received")]
+    PacketTimedOut { sequence: u64, channel: String },
+    #[error("packet {sequence} on {channel} was acknowledged with an error: {ack:?}")]
+    AckError {
+        sequence: u64,
+        channel: String,
+        ack: String,
+    },
+    #[error("the denom trace of `{denom}` hashes to `{trace_denom}`")]
+    DenomTraceMismatch { denom: String, trace_denom: String },
+    #[error("the balance of {denom} held by {addr} changed by {actual}, expected {expected}")]
+    BalanceMismatch {
+        addr: String,
+        denom: String,
+        expected: i128,
+        actual: i128,
+    },
     #[error("the HTTP client encountered an error: `{0}`")]
     HttpError(#[from] ReqwestError),
 }
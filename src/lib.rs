@@ -1,16 +1,33 @@
 pub mod error;
+/// Utilities for exercising a TestContext without a running local-ic instance.
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod types;
 pub mod utils;
 
 /// A builder for the testing environment harness.
 pub use utils::test_context::TestContextBuilder;
 
+/// An async handle to a TestContext, for use from within a tokio runtime.
+#[cfg(feature = "async")]
+pub use utils::async_context::AsyncTestContext;
+
 /// A builder for localic chain configs.
 pub use types::config::ConfigChainBuilder;
 
+/// A registry of chain configs, looked up by chain name.
+pub use types::registry::ChainRegistry;
+
+/// Gas, fees, and other flags of a transaction.
+pub use types::tx::{Gas, TxOptions, TxOptionsBuilder};
+
 /// The IBC port name for ibc transfers.
 pub const TRANSFER_PORT: &str = "transfer";
 
+/// The IBC ports of interchain security channels on provider and consumer chains.
+pub const CCV_PROVIDER_PORT: &str = "provider";
+pub const CCV_CONSUMER_PORT: &str = "consumer";
+
 /// File extension for WASM files
 pub const WASM_EXTENSION: &str = "wasm";
 
@@ -64,6 +81,9 @@ pub const PRICE_ORACLE_NAME: &str = "price_oracle";
 /// Local ic info
 pub const LOCAL_IC_API_URL: &str = "http://localhost:42069/";
 
+/// The local directory of interchain account packets before they are uploaded
+pub const ICA_PACKET_FILE_DIR: &str = "/tmp";
+
 /// Builder defautls
 pub const ADMIN_KEY: &str = "admin";
 pub const DEFAULT_KEY: &str = "acc0";
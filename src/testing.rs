@@ -0,0 +1,350 @@
+use super::error::Error;
+use log::{debug, warn};
+use serde_json::Value;
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+/// Actions that local-ic accepts for chain queries.
+const QUERY_ACTIONS: &[&str] = &["q", "query"];
+
+/// Actions that local-ic accepts for raw binary commands.
+const BIN_ACTIONS: &[&str] = &["b", "bin", "binary"];
+
+/// Actions that local-ic accepts for transactions.
+const TX_ACTIONS: &[&str] = &["tx"];
+
+/// A request received by a MockLocalIc.
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: String,
+    pub path: String,
+    pub body: Value,
+}
+
+impl MockRequest {
+    /// The local-ic action requested, e.g. `q`, `bin`, or `relayer-exec`.
+    pub fn action(&self) -> Option<&str> {
+        self.body.get("action").and_then(Value::as_str)
+    }
+
+    pub fn chain_id(&self) -> Option<&str> {
+        self.body.get("chain_id").and_then(Value::as_str)
+    }
+
+    pub fn cmd(&self) -> Option<&str> {
+        self.body.get("cmd").and_then(Value::as_str)
+    }
+}
+
+/// A scripted response served for every request matching its filters.
+struct MockRoute {
+    path: String,
+    actions: Vec<String>,
+    chain_id: Option<String>,
+    cmd_contains: Option<String>,
+    body: String,
+}
+
+impl MockRoute {
+    fn matches(&self, req: &MockRequest) -> bool {
+        self.path == req.path
+            && (self.actions.is_empty()
+                || req
+                    .action()
+                    .is_some_and(|action| self.actions.iter().any(|a| a == action)))
+            && (self.chain_id.is_none() || req.chain_id() == self.chain_id.as_deref())
+            && self.cmd_contains.iter().all(|cmd| {
+                req.cmd()
+                    .is_some_and(|req_cmd| req_cmd.contains(cmd.as_str()))
+            })
+    }
+}
+
+#[derive(Default)]
+struct MockState {
+    routes: Vec<MockRoute>,
+    requests: Vec<MockRequest>,
+}
+
+/// An in-process stand-in for the local-ic HTTP API, serving scripted
+/// responses so that a TestContext can be exercised without running chains.
+///
+/// Responses are matched against the most recently registered route first.
+/// Unmatched requests receive a 404.
+pub struct MockLocalIc {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockLocalIc {
+    /// Starts the mock server on a random local port.
+    pub fn start() -> Result<Self, Error> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let state = state.clone();
+            let shutdown = shutdown.clone();
+
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    let Ok(stream) = stream else {
+                        continue;
+                    };
+
+                    if let Err(e) = serve(stream, &state) {
+                        warn!("mock local-ic failed to serve request: {e}");
+                    }
+                }
+            })
+        };
+
+        let mock = Self {
+            addr,
+            state,
+            shutdown,
+            handle: Some(handle),
+        };
+
+        // Chains without scripted channels have none
+        mock.on_route(MockRoute {
+            path: String::from("/"),
+            actions: vec![String::from("get_channels")],
+            chain_id: None,
+            cmd_contains: None,
+            body: String::from("[]"),
+        });
+
+        Ok(mock)
+    }
+
+    /// The URL to pass to `TestContextBuilder::with_api_url`.
+    pub fn api_url(&self) -> String {
+        format!("http://{}/", self.addr)
+    }
+
+    /// Serves the body for any action whose command contains the given string.
+    pub fn on_cmd(&self, cmd_contains: &str, body: impl Into<String>) -> &Self {
+        self.on_actions(&[], cmd_contains, body)
+    }
+
+    /// Serves the body for a specific action whose command contains the given string.
+    pub fn on_action(&self, action: &str, cmd_contains: &str, body: impl Into<String>) -> &Self {
+        self.on_actions(&[action], cmd_contains, body)
+    }
+
+    /// Serves the body for queries (`ChainRequestBuilder::q` and `query`).
+    pub fn on_query(&self, cmd_contains: &str, body: impl Into<String>) -> &Self {
+        self.on_actions(QUERY_ACTIONS, cmd_contains, body)
+    }
+
+    /// Serves the body for binary commands (`ChainRequestBuilder::bin`).
+    pub fn on_bin(&self, cmd_contains: &str, body: impl Into<String>) -> &Self {
+        self.on_actions(BIN_ACTIONS, cmd_contains, body)
+    }
+
+    /// Serves the body for transactions (`ChainRequestBuilder::tx`).
+    pub fn on_tx(&self, cmd_contains: &str, body: impl Into<String>) -> &Self {
+        self.on_actions(TX_ACTIONS, cmd_contains, body)
+    }
+
+    /// Serves the body for relayer commands, e.g. `rly q channels`.
+    pub fn on_relayer(&self, cmd_contains: &str, body: impl Into<String>) -> &Self {
+        self.on_action("relayer-exec", cmd_contains, body)
+    }
+
+    /// Serves the channels returned by `Relayer::get_channels` for a chain.
+    pub fn on_channels(&self, chain_id: &str, channels: Value) -> &Self {
+        self.on_route(MockRoute {
+            path: String::from("/"),
+            actions: vec![String::from("get_channels")],
+            chain_id: Some(chain_id.to_owned()),
+            cmd_contains: None,
+            body: channels.to_string(),
+        })
+    }
+
+    /// Serves the body for file and contract uploads.
+    pub fn on_upload(&self, body: impl Into<String>) -> &Self {
+        self.on_route(MockRoute {
+            path: String::from("/upload"),
+            actions: vec![],
+            chain_id: None,
+            cmd_contains: None,
+            body: body.into(),
+        })
+    }
+
+    /// Serves the logs returned by `GET /info?request=logs`.
+    pub fn on_logs(&self, logs: Value) -> &Self {
+        self.on_route(MockRoute {
+            path: String::from("/info"),
+            actions: vec![],
+            chain_id: None,
+            cmd_contains: None,
+            body: logs.to_string(),
+        })
+    }
+
+    /// Gets all requests received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state
+            .lock()
+            .map(|state| state.requests.clone())
+            .unwrap_or_default()
+    }
+
+    fn on_actions(&self, actions: &[&str], cmd_contains: &str, body: impl Into<String>) -> &Self {
+        self.on_route(MockRoute {
+            path: String::from("/"),
+            actions: actions.iter().map(|a| a.to_string()).collect(),
+            chain_id: None,
+            cmd_contains: Some(cmd_contains.to_owned()),
+            body: body.into(),
+        })
+    }
+
+    fn on_route(&self, route: MockRoute) -> &Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.routes.push(route);
+        }
+
+        self
+    }
+}
+
+impl Drop for MockLocalIc {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+
+        // Wake up the listener so that it observes the shutdown
+        let _ = TcpStream::connect(self.addr);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Reads one HTTP request from the stream and writes the matching response.
+fn serve(mut stream: TcpStream, state: &Mutex<MockState>) -> Result<(), Error> {
+    let req = read_request(&mut stream)?;
+    debug!(
+        "mock local-ic received {} {} {}",
+        req.method, req.path, req.body
+    );
+
+    let body = {
+        let mut state = state
+            .lock()
+            .map_err(|_| Error::Misc(String::from("mock local-ic state poisoned")))?;
+        let body = state
+            .routes
+            .iter()
+            .rev()
+            .find(|route| route.matches(&req))
+            .map(|route| route.body.clone());
+        state.requests.push(req);
+
+        body
+    };
+
+    let (status, body) = match body {
+        Some(body) => ("200 OK", body),
+        None => ("404 Not Found", String::from("no mock response")),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+
+    Ok(())
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<MockRequest, Error> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_owned();
+    let path = parts
+        .next()
+        .and_then(|target| target.split('?').next())
+        .unwrap_or("/")
+        .to_owned();
+
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or_default();
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(MockRequest {
+        method,
+        path,
+        body: serde_json::from_slice(&body).unwrap_or(Value::Null),
+    })
+}
+
+/// Builds a TestContext for neutron and osmosis whose logs are served by the mock.
+#[cfg(test)]
+pub(crate) fn mock_context_builder(mock: &MockLocalIc) -> crate::TestContextBuilder {
+    use crate::{ConfigChainBuilder, TestContextBuilder};
+
+    mock.on_logs(serde_json::json!({
+        "start_time": 1,
+        "chains": [],
+        "ibc_channels": [],
+    }));
+
+    let mut builder = TestContextBuilder::default();
+    builder
+        .with_api_url(mock.api_url())
+        .with_artifacts_dir("contracts")
+        .with_log_file_path("/nonexistent/logs.json")
+        .with_logs_from_api(true)
+        .with_chain(ConfigChainBuilder::default_neutron().build().unwrap())
+        .with_chain(ConfigChainBuilder::default_osmosis().build().unwrap());
+
+    builder
+}
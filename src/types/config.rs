@@ -1,20 +1,12 @@
-use crate::{
-    GAIA_CHAIN_ADMIN_ADDR, GAIA_CHAIN_DENOM, GAIA_CHAIN_ID, GAIA_CHAIN_NAME, GAIA_CHAIN_PREFIX,
-    JUNO_CHAIN_ADMIN_ADDR, JUNO_CHAIN_DENOM, JUNO_CHAIN_ID, JUNO_CHAIN_NAME, JUNO_CHAIN_PREFIX,
-    NEUTRON_CHAIN_ID,
-};
+use crate::{GAIA_CHAIN_NAME, JUNO_CHAIN_NAME};
 
-use super::super::{
-    NEUTRON_CHAIN_ADMIN_ADDR, NEUTRON_CHAIN_DENOM, NEUTRON_CHAIN_NAME, NEUTRON_CHAIN_PREFIX,
-    OSMOSIS_CHAIN_ADMIN_ADDR, OSMOSIS_CHAIN_DENOM, OSMOSIS_CHAIN_ID, OSMOSIS_CHAIN_NAME,
-    OSMOSIS_CHAIN_PREFIX, STRIDE_CHAIN_ADMIN_ADDR, STRIDE_CHAIN_DENOM, STRIDE_CHAIN_ID,
-    STRIDE_CHAIN_NAME, STRIDE_CHAIN_PREFIX,
-};
+use super::super::{NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, STRIDE_CHAIN_NAME};
+use super::{registry::ChainRegistry, tx::TxOptions};
 use derive_builder::Builder;
 use serde::Deserialize;
 
 /// Struct representing the contents of config/logs.json
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct Logs {
     pub start_time: u64,
     pub chains: Vec<LogsChainEntry>,
@@ -22,7 +14,7 @@ pub struct Logs {
 }
 
 /// Represents a chain entry in the logs file
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct LogsChainEntry {
     pub chain_id: String,
     pub chain_name: String,
@@ -33,14 +25,14 @@ pub struct LogsChainEntry {
 }
 
 /// Represents an IBC channel entry in the logs file
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct LogsChannelEntry {
     pub chain_id: String,
     pub channel: LogsChannel,
 }
 
 /// Represents the channel info in a channel entry
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct LogsChannel {
     pub state: String,
     pub ordering: String,
@@ -52,7 +44,7 @@ pub struct LogsChannel {
 }
 
 /// Represents counterparty info in a channel entry
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct LogsCounterparty {
     pub port_id: String,
     pub channel_id: String,
@@ -74,66 +66,42 @@ impl From<ChainsVec> for Vec<ConfigChain> {
 #[builder(setter(into, prefix = "with"))]
 pub struct ConfigChain {
     pub denom: String,
+    #[serde(default)]
     pub debugging: bool,
     pub chain_id: String,
     pub chain_name: String,
     pub chain_prefix: String,
     pub admin_addr: String,
+    /// The default gas, fees, and flags of transactions sent on the chain
+    #[serde(default)]
+    #[builder(default)]
+    pub tx_options: TxOptions,
 }
 
 impl ConfigChainBuilder {
     pub fn default_gaia() -> Self {
-        Self {
-            denom: Some(String::from(GAIA_CHAIN_DENOM)),
-            debugging: Some(true),
-            chain_id: Some(String::from(GAIA_CHAIN_ID)),
-            chain_name: Some(String::from(GAIA_CHAIN_NAME)),
-            chain_prefix: Some(String::from(GAIA_CHAIN_PREFIX)),
-            admin_addr: Some(String::from(GAIA_CHAIN_ADMIN_ADDR)),
-        }
+        Self::builtin(GAIA_CHAIN_NAME)
     }
 
     pub fn default_neutron() -> Self {
-        Self {
-            denom: Some(String::from(NEUTRON_CHAIN_DENOM)),
-            debugging: Some(true),
-            chain_id: Some(String::from(NEUTRON_CHAIN_ID)),
-            chain_name: Some(String::from(NEUTRON_CHAIN_NAME)),
-            chain_prefix: Some(String::from(NEUTRON_CHAIN_PREFIX)),
-            admin_addr: Some(String::from(NEUTRON_CHAIN_ADMIN_ADDR)),
-        }
+        Self::builtin(NEUTRON_CHAIN_NAME)
     }
 
     pub fn default_osmosis() -> Self {
-        Self {
-            denom: Some(String::from(OSMOSIS_CHAIN_DENOM)),
-            debugging: Some(true),
-            chain_id: Some(String::from(OSMOSIS_CHAIN_ID)),
-            chain_name: Some(String::from(OSMOSIS_CHAIN_NAME)),
-            chain_prefix: Some(String::from(OSMOSIS_CHAIN_PREFIX)),
-            admin_addr: Some(String::from(OSMOSIS_CHAIN_ADMIN_ADDR)),
-        }
+        Self::builtin(OSMOSIS_CHAIN_NAME)
     }
 
     pub fn default_stride() -> Self {
-        Self {
-            denom: Some(String::from(STRIDE_CHAIN_DENOM)),
-            debugging: Some(true),
-            chain_id: Some(String::from(STRIDE_CHAIN_ID)),
-            chain_name: Some(String::from(STRIDE_CHAIN_NAME)),
-            chain_prefix: Some(String::from(STRIDE_CHAIN_PREFIX)),
-            admin_addr: Some(String::from(STRIDE_CHAIN_ADMIN_ADDR)),
-        }
+        Self::builtin(STRIDE_CHAIN_NAME)
     }
 
     pub fn default_juno() -> Self {
-        Self {
-            denom: Some(String::from(JUNO_CHAIN_DENOM)),
-            debugging: Some(true),
-            chain_id: Some(String::from(JUNO_CHAIN_ID)),
-            chain_name: Some(String::from(JUNO_CHAIN_NAME)),
-            chain_prefix: Some(String::from(JUNO_CHAIN_PREFIX)),
-            admin_addr: Some(String::from(JUNO_CHAIN_ADMIN_ADDR)),
-        }
+        Self::builtin(JUNO_CHAIN_NAME)
+    }
+
+    /// Creates a builder prepopulated with a chain bundled with localic-utils.
+    fn builtin(chain_name: &str) -> Self {
+        Self::from_registry(&ChainRegistry::builtin(), chain_name)
+            .expect("bundled chain configs include the default chains")
     }
 }
@@ -1,7 +1,31 @@
+use super::{super::error::Error, config::LogsChannel, tx::TxReceipt};
 use crate::TRANSFER_PORT;
-use serde::Deserialize;
+use cosmwasm_std::Binary;
+use localic_std::relayer::Channel as RelayerChannel;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+/// The acknowledgement written by ICS-20 transfer modules for successfully received packets.
+pub const ICS20_SUCCESS_ACK: &str = r#"{"result":"AQ=="}"#;
+
+/// The ordering of packets on a channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChannelOrder {
+    Ordered,
+    #[default]
+    Unordered,
+}
+
+impl ChannelOrder {
+    /// The value of the relayer's `--order` flag.
+    pub fn as_flag(&self) -> &'static str {
+        match self {
+            Self::Ordered => "ordered",
+            Self::Unordered => "unordered",
+        }
+    }
+}
+
 #[derive(Deserialize)]
 pub struct Channel {
     pub channel_id: String,
@@ -19,7 +43,128 @@ pub struct Counterparty {
     pub port_id: String,
 }
 
-#[derive(Debug)]
+/// One end of a channel between two chains, as tracked by a TestContext.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct ChannelInfo {
+    pub channel_id: String,
+    /// The connection on this end of the channel
+    pub connection_id: String,
+    pub counterparty_channel_id: String,
+    pub counterparty_port_id: String,
+    /// Either ORDER_ORDERED or ORDER_UNORDERED
+    pub ordering: String,
+    pub version: String,
+}
+
+impl ChannelInfo {
+    /// Creates a channel whose ID is the only thing known about it,
+    /// e.g. one passed to a TestContextBuilder.
+    pub fn from_id(channel_id: impl Into<String>) -> Self {
+        Self {
+            channel_id: channel_id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a channel from the fields shared by the relayer, query, and logs
+    /// representations of a channel.
+    fn new(
+        channel_id: &str,
+        connection_hops: &[String],
+        counterparty_channel_id: &str,
+        counterparty_port_id: &str,
+        ordering: &str,
+        version: &str,
+    ) -> Self {
+        Self {
+            channel_id: channel_id.to_owned(),
+            connection_id: connection_hops.first().cloned().unwrap_or_default(),
+            counterparty_channel_id: counterparty_channel_id.to_owned(),
+            counterparty_port_id: counterparty_port_id.to_owned(),
+            ordering: ordering.to_owned(),
+            version: version.to_owned(),
+        }
+    }
+}
+
+impl From<&Channel> for ChannelInfo {
+    fn from(channel: &Channel) -> Self {
+        Self::new(
+            &channel.channel_id,
+            &channel.connection_hops,
+            &channel.counterparty.channel_id,
+            &channel.counterparty.port_id,
+            &channel.ordering,
+            &channel.version,
+        )
+    }
+}
+
+impl From<&RelayerChannel> for ChannelInfo {
+    fn from(channel: &RelayerChannel) -> Self {
+        Self::new(
+            &channel.channel_id,
+            &channel.connection_hops,
+            &channel.counterparty.channel_id,
+            &channel.counterparty.port_id,
+            &channel.ordering,
+            &channel.version,
+        )
+    }
+}
+
+impl From<&LogsChannel> for ChannelInfo {
+    fn from(channel: &LogsChannel) -> Self {
+        Self::new(
+            &channel.channel_id,
+            &channel.connection_hops,
+            &channel.counterparty.channel_id,
+            &channel.counterparty.port_id,
+            &channel.ordering,
+            &channel.version,
+        )
+    }
+}
+
+/// An IBC packet sent by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub sequence: u64,
+    pub src_port: String,
+    pub src_channel: String,
+    pub dst_port: String,
+    pub dst_channel: String,
+}
+
+impl Packet {
+    /// Gets the packet sent by a transaction from its `send_packet` event.
+    pub fn from_receipt(receipt: &TxReceipt) -> Result<Self, Error> {
+        let event = receipt.find_event("send_packet")?;
+        let attr = |key: &str| {
+            event
+                .attr(key)
+                .map(ToOwned::to_owned)
+                .ok_or_else(|| Error::MissingContextVariable(format!("send_packet::{key}")))
+        };
+
+        Ok(Self {
+            sequence: attr("packet_sequence")?
+                .parse()
+                .map_err(|_| Error::MissingContextVariable(String::from("packet_sequence")))?,
+            src_port: attr("packet_src_port")?,
+            src_channel: attr("packet_src_channel")?,
+            dst_port: attr("packet_dst_port")?,
+            dst_channel: attr("packet_dst_channel")?,
+        })
+    }
+}
+
+/// Gets the base64 encoded commitment stored by a chain for an acknowledgement.
+pub fn get_ack_commitment(ack: &str) -> String {
+    Binary::from(Sha256::digest(ack.as_bytes()).as_slice()).to_base64()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct DenomTrace {
     pub path: String,
     pub base_denom: String,
@@ -5,3 +5,9 @@ pub mod config;
 pub mod contract;
 
 pub mod ibc;
+
+/// A registry of chain configs, looked up by chain name.
+pub mod registry;
+
+/// Types describing transactions and their results.
+pub mod tx;
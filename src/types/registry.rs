@@ -0,0 +1,173 @@
+use super::{
+    super::{error::Error, DEFAULT_KEY},
+    config::{ChainsVec, ConfigChain, ConfigChainBuilder},
+};
+use serde::Deserialize;
+use std::{collections::HashMap, fs, path::Path};
+
+/// The configs of the chains bundled with localic-utils.
+const BUILTIN_CHAINS_JSON: &str = include_str!("chains.json");
+
+/// A set of chain configs, looked up by chain name.
+///
+/// Chains may be loaded from JSON or TOML files containing a list of
+/// `ConfigChain`s under a `chains` key, or from local-ic chain config files.
+#[derive(Clone, Default, Debug)]
+pub struct ChainRegistry {
+    chains: HashMap<String, ConfigChain>,
+}
+
+/// The subset of a local-ic chain config (e.g. chains/neutron_gaia.json) describing a chain.
+#[derive(Deserialize)]
+struct LocalIcChainConfig {
+    name: String,
+    chain_id: String,
+    denom: String,
+    bech32_prefix: String,
+    #[serde(default)]
+    debugging: bool,
+    #[serde(default)]
+    genesis: LocalIcGenesis,
+}
+
+#[derive(Deserialize, Default)]
+struct LocalIcGenesis {
+    #[serde(default)]
+    accounts: Vec<LocalIcGenesisAccount>,
+}
+
+#[derive(Deserialize)]
+struct LocalIcGenesisAccount {
+    name: String,
+    address: String,
+}
+
+#[derive(Deserialize)]
+struct LocalIcChainsConfig {
+    chains: Vec<LocalIcChainConfig>,
+}
+
+impl ChainRegistry {
+    /// Creates a registry containing the chains bundled with localic-utils.
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        registry
+            .parse_json(BUILTIN_CHAINS_JSON)
+            .expect("bundled chain configs are valid");
+
+        registry
+    }
+
+    /// Adds a chain to the registry, replacing any chain with the same name.
+    pub fn insert(&mut self, chain: ConfigChain) -> &mut Self {
+        self.chains.insert(chain.chain_name.clone(), chain);
+
+        self
+    }
+
+    /// Gets the config of a chain by its name.
+    pub fn get(&self, chain_name: &str) -> Option<&ConfigChain> {
+        self.chains.get(chain_name)
+    }
+
+    /// Gets the config of a chain by its chain ID.
+    pub fn get_by_chain_id(&self, chain_id: &str) -> Option<&ConfigChain> {
+        self.chains
+            .values()
+            .find(|chain| chain.chain_id == chain_id)
+    }
+
+    /// Gets the config of a chain by its name, erroring if it is not registered.
+    pub fn chain(&self, chain_name: &str) -> Result<ConfigChain, Error> {
+        self.get(chain_name)
+            .cloned()
+            .ok_or_else(|| Error::MissingContextVariable(format!("chain_registry::{chain_name}")))
+    }
+
+    pub fn chains(&self) -> impl Iterator<Item = &ConfigChain> {
+        self.chains.values()
+    }
+
+    /// Adds the chains listed in a JSON file of the form `{ "chains": [ConfigChain] }`.
+    pub fn load_json(&mut self, path: impl AsRef<Path>) -> Result<&mut Self, Error> {
+        self.parse_json(&fs::read_to_string(path)?)
+    }
+
+    /// Adds the chains listed in a TOML file as a `[[chains]]` array of `ConfigChain`s.
+    pub fn load_toml(&mut self, path: impl AsRef<Path>) -> Result<&mut Self, Error> {
+        let chains: ChainsVec = toml::from_str(&fs::read_to_string(path)?)?;
+
+        Ok(self.extend(chains))
+    }
+
+    /// Adds the chains in a local-ic chain config file (e.g. chains/neutron_gaia.json).
+    /// The admin address of each chain is the genesis account of the default key,
+    /// or the first genesis account if there is none.
+    pub fn load_local_ic_config(&mut self, path: impl AsRef<Path>) -> Result<&mut Self, Error> {
+        let config: LocalIcChainsConfig = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        for chain in config.chains {
+            let admin_addr = chain
+                .genesis
+                .accounts
+                .iter()
+                .find(|acc| acc.name == DEFAULT_KEY)
+                .or_else(|| chain.genesis.accounts.first())
+                .map(|acc| acc.address.clone())
+                .unwrap_or_default();
+
+            self.insert(ConfigChain {
+                denom: chain.denom,
+                debugging: chain.debugging,
+                chain_id: chain.chain_id,
+                chain_name: chain.name,
+                chain_prefix: chain.bech32_prefix,
+                admin_addr,
+                tx_options: Default::default(),
+            });
+        }
+
+        Ok(self)
+    }
+
+    fn parse_json(&mut self, json: &str) -> Result<&mut Self, Error> {
+        let chains: ChainsVec = serde_json::from_str(json)?;
+
+        Ok(self.extend(chains))
+    }
+
+    fn extend(&mut self, chains: ChainsVec) -> &mut Self {
+        for chain in Vec::from(chains) {
+            self.insert(chain);
+        }
+
+        self
+    }
+}
+
+impl ConfigChainBuilder {
+    /// Creates a builder prepopulated with a chain's config from the registry.
+    pub fn from_registry(registry: &ChainRegistry, chain_name: &str) -> Result<Self, Error> {
+        let ConfigChain {
+            denom,
+            debugging,
+            chain_id,
+            chain_name,
+            chain_prefix,
+            admin_addr,
+            tx_options,
+        } = registry.chain(chain_name)?;
+
+        let mut builder = Self::default();
+        builder
+            .with_denom(denom)
+            .with_debugging(debugging)
+            .with_chain_id(chain_id)
+            .with_chain_name(chain_name)
+            .with_chain_prefix(chain_prefix)
+            .with_admin_addr(admin_addr)
+            .with_tx_options(tx_options);
+
+        Ok(builder)
+    }
+}
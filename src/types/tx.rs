@@ -0,0 +1,309 @@
+use crate::error::Error;
+use cosmwasm_std::{Coin, Uint128};
+use derive_builder::Builder;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// The outcome of a transaction that was included in a block.
+#[derive(Debug, Clone, Default)]
+pub struct TxReceipt {
+    pub hash: String,
+    pub height: u64,
+    pub gas_wanted: u64,
+    pub gas_used: u64,
+    pub code: u32,
+    pub raw_log: String,
+    pub events: Vec<TxEvent>,
+}
+
+/// An ABCI event emitted by a transaction.
+#[derive(Debug, Clone, Default)]
+pub struct TxEvent {
+    pub kind: String,
+    pub attributes: Vec<EventAttribute>,
+    /// The index of the message in the transaction that emitted the event.
+    /// Events emitted by the ante handler (fees, signatures) have no index.
+    pub msg_index: Option<u32>,
+}
+
+/// A key-value pair attached to an event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+/// The gas limit of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gas {
+    /// Estimates the gas by simulating the transaction
+    Auto,
+    Limit(u64),
+}
+
+/// Gas, fees, and other flags of a transaction. Options that are not set fall back to
+/// the defaults of the chain the transaction is sent on, and then to the transaction's own defaults.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Builder)]
+#[builder(setter(into, strip_option, prefix = "with"), default)]
+pub struct TxOptions {
+    pub gas: Option<Gas>,
+    /// The factor the estimated gas is multiplied by
+    pub gas_adjustment: Option<f64>,
+    pub fee_amount: Option<u128>,
+    /// Defaults to the chain's native denom
+    pub fee_denom: Option<String>,
+    /// The memo of IBC packets sent by the transaction. Only used by transfers.
+    pub memo: Option<String>,
+    /// The note attached to the transaction
+    pub note: Option<String>,
+    /// The block height after which the transaction may not be included
+    pub timeout_height: Option<u64>,
+}
+
+impl TxOptions {
+    /// Options with nothing set, for building options in constants.
+    pub const EMPTY: TxOptions = TxOptions {
+        gas: None,
+        gas_adjustment: None,
+        fee_amount: None,
+        fee_denom: None,
+        memo: None,
+        note: None,
+        timeout_height: None,
+    };
+
+    /// Fills the options that are not set from another set of options.
+    pub fn or(&self, defaults: &TxOptions) -> TxOptions {
+        TxOptions {
+            gas: self.gas.or(defaults.gas),
+            gas_adjustment: self.gas_adjustment.or(defaults.gas_adjustment),
+            fee_amount: self.fee_amount.or(defaults.fee_amount),
+            fee_denom: self
+                .fee_denom
+                .clone()
+                .or_else(|| defaults.fee_denom.clone()),
+            memo: self.memo.clone().or_else(|| defaults.memo.clone()),
+            note: self.note.clone().or_else(|| defaults.note.clone()),
+            timeout_height: self.timeout_height.or(defaults.timeout_height),
+        }
+    }
+
+    /// Formats the options as CLI flags, each preceded by a space.
+    /// The memo is not included, since it is only a flag of transfers.
+    /// Errors if the note contains whitespace.
+    pub fn to_flags(&self, native_denom: &str) -> Result<String, Error> {
+        let mut flags = String::new();
+
+        if let Some(fee_amount) = self.fee_amount {
+            let fee_denom = self.fee_denom.as_deref().unwrap_or(native_denom);
+
+            flags.push_str(&format!(" --fees={fee_amount}{fee_denom}"));
+        }
+
+        match self.gas {
+            Some(Gas::Auto) => flags.push_str(" --gas=auto"),
+            Some(Gas::Limit(limit)) => flags.push_str(&format!(" --gas={limit}")),
+            None => {}
+        }
+
+        if let Some(gas_adjustment) = self.gas_adjustment {
+            flags.push_str(&format!(" --gas-adjustment={gas_adjustment}"));
+        }
+
+        if let Some(note) = &self.note {
+            flags.push_str(&flag("note", note)?);
+        }
+
+        if let Some(timeout_height) = self.timeout_height {
+            flags.push_str(&format!(" --timeout-height={timeout_height}"));
+        }
+
+        Ok(flags)
+    }
+}
+
+impl TxReceipt {
+    /// Creates a receipt from the JSON response of a `q tx` query.
+    pub fn from_tx_response(hash: &str, tx_res: &Value) -> Self {
+        Self {
+            hash: tx_res
+                .get("txhash")
+                .and_then(Value::as_str)
+                .unwrap_or(hash)
+                .to_owned(),
+            height: parse_u64(&tx_res["height"]),
+            gas_wanted: parse_u64(&tx_res["gas_wanted"]),
+            gas_used: parse_u64(&tx_res["gas_used"]),
+            code: parse_u64(&tx_res["code"]) as u32,
+            raw_log: tx_res["raw_log"].as_str().unwrap_or_default().to_owned(),
+            events: tx_res
+                .get("events")
+                .and_then(Value::as_array)
+                .map(|events| events.iter().map(TxEvent::from_value).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Gets all events of the given type, in the order they were emitted.
+    pub fn find_events<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a TxEvent> {
+        self.events.iter().filter(move |event| event.kind == kind)
+    }
+
+    /// Gets the first event of the given type, or returns an error if
+    /// the transaction emitted no such event.
+    pub fn find_event(&self, kind: &str) -> Result<&TxEvent, Error> {
+        self.assert_event_emitted(kind, &[])
+    }
+
+    /// Gets the first event of the given type carrying all of the given
+    /// attributes, or returns an error if no such event was emitted.
+    pub fn assert_event_emitted(
+        &self,
+        kind: &str,
+        attributes: &[(&str, &str)],
+    ) -> Result<&TxEvent, Error> {
+        self.events
+            .iter()
+            .filter(|event| event.kind == kind)
+            .find(|event| {
+                attributes
+                    .iter()
+                    .all(|(key, value)| event.attrs(key).any(|v| v == *value))
+            })
+            .ok_or_else(|| Error::EventNotEmitted {
+                hash: self.hash.clone(),
+                kind: kind.to_owned(),
+                attributes: attributes
+                    .iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .collect(),
+            })
+    }
+
+    /// Gets the fees paid for the transaction, from its `tx` events.
+    pub fn fees(&self) -> Vec<Coin> {
+        self.find_events("tx")
+            .flat_map(|event| event.attrs("fee"))
+            .flat_map(|fee| fee.split(','))
+            .filter_map(parse_coin)
+            .collect()
+    }
+
+    /// Gets the address that paid the transaction's fees. Before the SDK recorded
+    /// the fee payer, this is the first signer of the transaction.
+    pub fn fee_payer(&self) -> Option<&str> {
+        self.find_events("tx")
+            .find_map(|event| event.attr("fee_payer"))
+            .or_else(|| {
+                self.find_events("tx")
+                    .find_map(|event| event.attr("acc_seq"))
+                    .and_then(|acc_seq| acc_seq.split('/').next())
+            })
+    }
+}
+
+impl TxEvent {
+    fn from_value(event: &Value) -> Self {
+        let attributes: Vec<EventAttribute> = event
+            .get("attributes")
+            .and_then(Value::as_array)
+            .map(|attrs| {
+                attrs
+                    .iter()
+                    .map(|attr| EventAttribute {
+                        key: attr["key"].as_str().unwrap_or_default().to_owned(),
+                        value: attr["value"].as_str().unwrap_or_default().to_owned(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Since SDK v0.50, the emitting message is recorded as an attribute
+        let msg_index = attributes
+            .iter()
+            .find(|attr| attr.key == "msg_index")
+            .and_then(|attr| attr.value.parse().ok());
+
+        Self {
+            kind: event["type"].as_str().unwrap_or_default().to_owned(),
+            attributes,
+            msg_index,
+        }
+    }
+
+    /// Gets the value of the first attribute with the given key.
+    pub fn attr(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    }
+
+    /// Gets the values of all attributes with the given key.
+    pub fn attrs<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.attributes
+            .iter()
+            .filter(move |attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    }
+}
+
+/// Parses a coin of the form "100000untrn".
+fn parse_coin(coin: &str) -> Option<Coin> {
+    let coin = coin.trim();
+    let split = coin.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, denom) = coin.split_at(split);
+
+    Some(Coin {
+        denom: denom.to_owned(),
+        amount: Uint128::new(amount.parse().ok()?),
+    })
+}
+
+/// Formats a flag preceded by a space. Since local-ic splits commands on whitespace
+/// without a shell, values containing whitespace cannot be passed and are rejected.
+pub(crate) fn flag(name: &str, value: &str) -> Result<String, Error> {
+    if value.chars().any(char::is_whitespace) {
+        return Err(Error::InvalidFlagValue {
+            flag: name.to_owned(),
+            value: value.to_owned(),
+        });
+    }
+
+    Ok(format!(" --{name}={value}"))
+}
+
+/// Cosmos SDK JSON encodes 64-bit integers as strings, but some fields are plain numbers.
+fn parse_u64(v: &Value) -> u64 {
+    v.as_u64()
+        .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_flags_rejects_whitespace_in_note() {
+        let options = TxOptions {
+            note: Some(String::from("a note --gas=1")),
+            ..TxOptions::EMPTY
+        };
+
+        assert!(matches!(
+            options.to_flags("untrn"),
+            Err(Error::InvalidFlagValue { flag, .. }) if flag == "note"
+        ));
+        assert_eq!(
+            TxOptions {
+                note: Some(String::from("a-note")),
+                ..TxOptions::EMPTY
+            }
+            .to_flags("untrn")
+            .unwrap(),
+            " --note=a-note"
+        );
+    }
+}
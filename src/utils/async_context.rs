@@ -0,0 +1,220 @@
+use super::{
+    super::{error::Error, types::tx::TxReceipt, TX_HASH_QUERY_PAUSE_SEC, TX_HASH_QUERY_RETRIES},
+    queries::unwrap_tx_response,
+    test_context::{TestContext, TestContextBuilder},
+};
+use reqwest::Client;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// The interval at which chain heights are polled while waiting for blocks.
+const BLOCK_POLL_INTERVAL_MILLIS: u64 = 500;
+
+/// An async handle to a TestContext, suitable for use from within a tokio runtime.
+///
+/// Only the requests of `AsyncLocalChain` (heights, block waits, tx results, and
+/// raw queries and txs) and relayer control are async, and may proceed concurrently.
+/// All other TestContext helpers are called through `run`, which holds a lock on
+/// the whole context, so those calls are serialized even across chains.
+#[derive(Clone)]
+pub struct AsyncTestContext {
+    inner: Arc<Mutex<TestContext>>,
+    chains: HashMap<String, AsyncLocalChain>,
+}
+
+impl AsyncTestContext {
+    pub fn new(ctx: TestContext) -> Self {
+        let client = Client::default();
+
+        let chains = ctx
+            .chains
+            .iter()
+            .map(|(name, chain)| {
+                (
+                    name.clone(),
+                    AsyncLocalChain {
+                        client: client.clone(),
+                        api_url: chain.rb.api.clone(),
+                        chain_id: chain.rb.chain_id.clone(),
+                        chain_name: chain.chain_name.clone(),
+                        unwrap_logs: ctx.unwrap_logs,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            inner: Arc::new(Mutex::new(ctx)),
+            chains,
+        }
+    }
+
+    /// Runs a closure over the underlying TestContext on tokio's blocking thread pool.
+    /// Closures run one at a time, since each locks the context until it returns.
+    /// Useful for calling the synchronous tx and query helpers, e.g.:
+    ///
+    /// ```ignore
+    /// let receipt = ctx.run(|ctx| ctx.build_tx_transfer().with_amount(1).send()).await??;
+    /// ```
+    pub async fn run<T, F>(&self, f: F) -> Result<T, Error>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut TestContext) -> T + Send + 'static,
+    {
+        let inner = self.inner.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut ctx = inner
+                .lock()
+                .map_err(|_| Error::Misc(String::from("test context lock poisoned")))?;
+
+            Ok(f(&mut ctx))
+        })
+        .await
+        .map_err(|e| Error::Misc(format!("blocking task failed: {e}")))?
+    }
+
+    pub fn get_chain(&self, chain_name: &str) -> &AsyncLocalChain {
+        self.chains.get(chain_name).unwrap()
+    }
+
+    /// Waits for a transaction to be included on the chain and checks its logs for errors.
+    pub async fn guard_tx_errors(&self, chain_name: &str, hash: &str) -> Result<TxReceipt, Error> {
+        self.get_chain(chain_name).guard_tx_errors(hash).await
+    }
+
+    pub async fn start_relayer(&self) -> Result<(), Error> {
+        self.relayer_action("start-relayer").await
+    }
+
+    pub async fn stop_relayer(&self) -> Result<(), Error> {
+        self.relayer_action("stop-relayer").await
+    }
+
+    async fn relayer_action(&self, action: &str) -> Result<(), Error> {
+        // chain_id does not matter, since there is one relayer running
+        let chain = self
+            .chains
+            .values()
+            .next()
+            .ok_or_else(|| Error::MissingContextVariable(String::from("chain")))?;
+
+        chain.send_request(action, "").await?;
+
+        Ok(())
+    }
+}
+
+/// An async client for a single chain in a TestContext. Cheap to clone.
+#[derive(Clone)]
+pub struct AsyncLocalChain {
+    client: Client,
+    api_url: String,
+    pub chain_id: String,
+    pub chain_name: String,
+    unwrap_logs: bool,
+}
+
+impl AsyncLocalChain {
+    /// Sends a request to the local-ic API for this chain. Responses that are not
+    /// JSON are wrapped in a `{ "text": ... }` object.
+    pub async fn send_request(&self, action: &str, cmd: &str) -> Result<Value, Error> {
+        let text = self
+            .client
+            .post(&self.api_url)
+            .json(&serde_json::json!({
+                "chain_id": self.chain_id,
+                "action": action,
+                "cmd": cmd,
+            }))
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        Ok(serde_json::from_str(&text).unwrap_or_else(|_| serde_json::json!({ "text": text })))
+    }
+
+    pub async fn query(&self, cmd: &str) -> Result<Value, Error> {
+        self.send_request("q", cmd).await
+    }
+
+    pub async fn bin(&self, cmd: &str) -> Result<Value, Error> {
+        self.send_request("bin", cmd).await
+    }
+
+    pub async fn tx(&self, cmd: &str) -> Result<Value, Error> {
+        self.send_request("tx", cmd).await
+    }
+
+    pub async fn query_tx_hash(&self, hash: &str) -> Result<Value, Error> {
+        self.query(&format!("q tx {hash} --output=json")).await
+    }
+
+    pub async fn get_height(&self) -> Result<u64, Error> {
+        let mut resp = self.bin("status --node=%RPC%").await?;
+
+        // The status may be returned as text if the binary logs it
+        if let Some(text) = resp.get("text").and_then(Value::as_str) {
+            resp = serde_json::from_str(text)?;
+        }
+
+        resp.get("sync_info")
+            .or_else(|| resp.get("SyncInfo"))
+            .and_then(|info| info.get("latest_block_height"))
+            .and_then(Value::as_str)
+            .and_then(|height| height.parse().ok())
+            .ok_or_else(|| Error::ContainerCmd(String::from("status")))
+    }
+
+    pub async fn wait_for_blocks(&self, blocks: u64) -> Result<(), Error> {
+        let current_height = self.get_height().await?;
+
+        while self.get_height().await? < current_height + blocks {
+            tokio::time::sleep(Duration::from_millis(BLOCK_POLL_INTERVAL_MILLIS)).await;
+        }
+
+        Ok(())
+    }
+
+    /// Waits for a transaction to be included on the chain and checks its logs for errors.
+    pub async fn guard_tx_errors(&self, hash: &str) -> Result<TxReceipt, Error> {
+        if !self.unwrap_logs {
+            return Ok(TxReceipt {
+                hash: hash.to_owned(),
+                ..Default::default()
+            });
+        }
+
+        for _ in 0..TX_HASH_QUERY_RETRIES {
+            tokio::time::sleep(Duration::from_secs(TX_HASH_QUERY_PAUSE_SEC)).await;
+
+            let res = self.query_tx_hash(hash).await?;
+
+            if res.get("raw_log").is_none() {
+                continue;
+            }
+
+            return unwrap_tx_response(hash, &res);
+        }
+
+        Err(Error::TxMissingLogs)
+    }
+}
+
+impl TestContextBuilder {
+    /// Builds an AsyncTestContext from the specified options without blocking the runtime.
+    pub async fn build_async(&self) -> Result<AsyncTestContext, Error> {
+        let builder = self.clone();
+
+        let ctx = tokio::task::spawn_blocking(move || builder.build())
+            .await
+            .map_err(|e| Error::Misc(format!("blocking task failed: {e}")))??;
+
+        Ok(AsyncTestContext::new(ctx))
+    }
+}
@@ -0,0 +1,224 @@
+use super::{
+    super::{error::Error, types::tx::TxReceipt},
+    test_context::TestContext,
+};
+use std::collections::HashMap;
+
+/// Balances of accounts on a chain, recorded before sending transactions so that
+/// the exact change in each balance can be asserted afterwards.
+#[derive(Debug, Clone)]
+pub struct BalanceSnapshot {
+    chain_name: String,
+    /// Maps (addr, denom) to the balance when the snapshot was taken
+    balances: HashMap<(String, String), u128>,
+}
+
+impl BalanceSnapshot {
+    pub fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+
+    /// Gets the recorded balance of a denom held by an address.
+    pub fn balance(&self, addr: &str, denom: &str) -> Option<u128> {
+        self.balances
+            .get(&(addr.to_owned(), denom.to_owned()))
+            .copied()
+    }
+
+    /// Gets the change in the balance of a denom held by an address since the snapshot was taken.
+    pub fn delta(&self, ctx: &TestContext, addr: &str, denom: &str) -> Result<i128, Error> {
+        let before = self
+            .balance(addr, denom)
+            .ok_or(Error::MissingContextVariable(format!(
+                "balance_snapshot::{addr}-{denom}"
+            )))?;
+        let after = ctx
+            .get_balance()
+            .src(&self.chain_name)
+            .addr(addr)
+            .denom(denom)
+            .try_get()?;
+
+        Ok(after as i128 - before as i128)
+    }
+
+    /// Asserts that the balance of a denom held by an address changed by exactly the
+    /// expected amount, excluding the fees the address paid for the given transactions.
+    /// A transfer of 100untrn paying a fee of 5000untrn is asserted with an expected delta of -100.
+    pub fn assert_delta(
+        &self,
+        ctx: &TestContext,
+        addr: &str,
+        denom: &str,
+        expected: i128,
+        receipts: &[&TxReceipt],
+    ) -> Result<(), Error> {
+        let mut fees = 0i128;
+
+        for receipt in receipts {
+            // Events are only available if logs are unwrapped
+            let queried;
+            let receipt = if receipt.events.is_empty() {
+                queried = ctx.query_tx_receipt(&self.chain_name, &receipt.hash)?;
+
+                &queried
+            } else {
+                *receipt
+            };
+
+            if receipt.fee_payer() != Some(addr) {
+                continue;
+            }
+
+            fees += receipt
+                .fees()
+                .into_iter()
+                .filter(|coin| coin.denom == denom)
+                .map(|coin| coin.amount.u128() as i128)
+                .sum::<i128>();
+        }
+
+        let actual = self.delta(ctx, addr, denom)? + fees;
+
+        if actual != expected {
+            return Err(Error::BalanceMismatch {
+                addr: addr.to_owned(),
+                denom: denom.to_owned(),
+                expected,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl TestContext {
+    /// Records the balances of (addr, denom) pairs on a chain, e.g. before sending a transaction.
+    pub fn snapshot_balances(
+        &self,
+        chain_name: &str,
+        accounts: &[(&str, &str)],
+    ) -> Result<BalanceSnapshot, Error> {
+        let balances = accounts
+            .iter()
+            .map(|(addr, denom)| {
+                let balance = self
+                    .get_balance()
+                    .src(chain_name)
+                    .addr(addr)
+                    .denom(denom)
+                    .try_get()?;
+
+                Ok(((addr.to_string(), denom.to_string()), balance))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(BalanceSnapshot {
+            chain_name: chain_name.to_owned(),
+            balances,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::{
+            testing::{mock_context_builder, MockLocalIc},
+            NEUTRON_CHAIN_NAME,
+        },
+        *,
+    };
+    use serde_json::json;
+
+    const SENDER: &str = "neutron1sender";
+    const RECEIVER: &str = "neutron1receiver";
+
+    fn on_balance(mock: &MockLocalIc, addr: &str, amount: u128) {
+        mock.on_query(
+            &format!("bank balances {addr}"),
+            json!({ "balances": [{ "denom": "untrn", "amount": amount.to_string() }] }).to_string(),
+        );
+    }
+
+    /// A transfer of 100untrn from the sender to the receiver, paying a fee of 5000untrn.
+    fn send_receipt() -> TxReceipt {
+        TxReceipt::from_tx_response(
+            "ABC",
+            &json!({
+                "txhash": "ABC",
+                "code": 0,
+                "raw_log": "",
+                "events": [{
+                    "type": "tx",
+                    "attributes": [
+                        { "key": "fee", "value": "5000untrn" },
+                        { "key": "fee_payer", "value": SENDER },
+                    ],
+                }],
+            }),
+        )
+    }
+
+    #[test]
+    fn test_balance_snapshot_delta() {
+        let mock = MockLocalIc::start().unwrap();
+        on_balance(&mock, SENDER, 10000);
+        on_balance(&mock, RECEIVER, 0);
+
+        let ctx = mock_context_builder(&mock).build().unwrap();
+        let snapshot = ctx
+            .snapshot_balances(
+                NEUTRON_CHAIN_NAME,
+                &[(SENDER, "untrn"), (RECEIVER, "untrn")],
+            )
+            .unwrap();
+
+        on_balance(&mock, SENDER, 4900);
+        on_balance(&mock, RECEIVER, 100);
+
+        assert_eq!(snapshot.balance(SENDER, "untrn"), Some(10000));
+        assert_eq!(snapshot.delta(&ctx, SENDER, "untrn").unwrap(), -5100);
+        assert_eq!(snapshot.delta(&ctx, RECEIVER, "untrn").unwrap(), 100);
+        assert!(matches!(
+            snapshot.delta(&ctx, RECEIVER, "uosmo"),
+            Err(Error::MissingContextVariable(_))
+        ));
+    }
+
+    #[test]
+    fn test_balance_snapshot_assert_delta_excludes_fees_of_payer() {
+        let mock = MockLocalIc::start().unwrap();
+        on_balance(&mock, SENDER, 10000);
+        on_balance(&mock, RECEIVER, 0);
+
+        let ctx = mock_context_builder(&mock).build().unwrap();
+        let snapshot = ctx
+            .snapshot_balances(
+                NEUTRON_CHAIN_NAME,
+                &[(SENDER, "untrn"), (RECEIVER, "untrn")],
+            )
+            .unwrap();
+
+        on_balance(&mock, SENDER, 4900);
+        on_balance(&mock, RECEIVER, 100);
+
+        let receipt = send_receipt();
+
+        snapshot
+            .assert_delta(&ctx, SENDER, "untrn", -100, &[&receipt])
+            .unwrap();
+        snapshot
+            .assert_delta(&ctx, RECEIVER, "untrn", 100, &[&receipt])
+            .unwrap();
+        assert!(matches!(
+            snapshot.assert_delta(&ctx, RECEIVER, "untrn", 5100, &[&receipt]),
+            Err(Error::BalanceMismatch {
+                expected: 5100,
+                actual: 100,
+                ..
+            })
+        ));
+    }
+}
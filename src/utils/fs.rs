@@ -1,14 +1,27 @@
 use super::{
-    super::{error::Error, DEFAULT_KEY, NEUTRON_CHAIN_NAME, WASM_EXTENSION},
+    super::{
+        error::Error,
+        types::tx::{Gas, TxOptions},
+        DEFAULT_KEY, NEUTRON_CHAIN_NAME, WASM_EXTENSION,
+    },
     test_context::TestContext,
 };
-use localic_std::modules::cosmwasm::CosmWasm;
 use log::{error, info};
+use serde_json::Value;
 use std::{
     collections::HashMap,
     ffi::OsStr,
     fs::{self, File},
     io::{Read, Write},
+    path::Path,
+};
+
+/// The default options of contract uploads stored with a tx, whose gas is estimated.
+const STORE_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Auto),
+    gas_adjustment: Some(1.3),
+    fee_amount: Some(500000),
+    ..TxOptions::EMPTY
 };
 
 /// A tx uploading contract artifacts.
@@ -16,6 +29,7 @@ pub struct UploadContractsTxBuilder<'a> {
     key: Option<&'a str>,
     test_ctx: &'a mut TestContext,
     chain_name: Option<&'a str>,
+    tx_options: TxOptions,
 }
 
 impl<'a> UploadContractsTxBuilder<'a> {
@@ -31,6 +45,13 @@ impl<'a> UploadContractsTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the store txs.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
     pub fn send(&mut self) -> Result<(), Error> {
         self.test_ctx.tx_upload_contracts(
@@ -38,6 +59,7 @@ impl<'a> UploadContractsTxBuilder<'a> {
                 .ok_or(Error::MissingBuilderParam(String::from("key")))?,
             self.chain_name
                 .ok_or(Error::MissingBuilderParam(String::from("chain_name")))?,
+            &self.tx_options,
         )
     }
 
@@ -54,6 +76,7 @@ impl<'a> UploadContractsTxBuilder<'a> {
                 .ok_or(Error::MissingBuilderParam(String::from("chain_name")))?,
             path,
             local_cache_path,
+            &self.tx_options,
         )
     }
 
@@ -65,6 +88,7 @@ impl<'a> UploadContractsTxBuilder<'a> {
             self.chain_name
                 .ok_or(Error::MissingBuilderParam(String::from("chain_name")))?,
             path,
+            &self.tx_options,
         )
     }
 }
@@ -75,28 +99,38 @@ impl TestContext {
             key: Some(DEFAULT_KEY),
             test_ctx: self,
             chain_name: Some(NEUTRON_CHAIN_NAME),
+            tx_options: Default::default(),
         }
     }
 
-    fn tx_upload_contract(&mut self, key: &str, chain_name: &str, path: &str) -> Result<(), Error> {
+    fn tx_upload_contract(
+        &mut self,
+        key: &str,
+        chain_name: &str,
+        path: &str,
+        tx_options: &TxOptions,
+    ) -> Result<(), Error> {
         let path = fs::canonicalize(path)?;
 
-        let local_chain = self.get_mut_chain(chain_name);
-
-        let mut cw = CosmWasm::new(&local_chain.rb);
-
-        let code_id = cw.store(key, &path)?;
+        let code_id = self.store_contract(key, chain_name, &path, tx_options)?;
 
         let id = path
             .file_stem()
             .and_then(|stem| stem.to_str())
             .ok_or(Error::Misc(String::from("failed to format file path")))?;
-        local_chain.contract_codes.insert(id.to_string(), code_id);
+        self.get_mut_chain(chain_name)
+            .contract_codes
+            .insert(id.to_string(), code_id);
 
         Ok(())
     }
 
-    fn tx_upload_contracts(&mut self, key: &str, chain_name: &str) -> Result<(), Error> {
+    fn tx_upload_contracts(
+        &mut self,
+        key: &str,
+        chain_name: &str,
+        tx_options: &TxOptions,
+    ) -> Result<(), Error> {
         fs::read_dir(&self.artifacts_dir)?
             .filter_map(|dir_ent| dir_ent.ok())
             .filter(|dir_ent| {
@@ -106,17 +140,16 @@ impl TestContext {
             .map(fs::canonicalize)
             .try_for_each(|maybe_abs_path| {
                 let path = maybe_abs_path?;
-                let local_chain = self.get_mut_chain(chain_name);
 
-                let mut cw = CosmWasm::new(&local_chain.rb);
-
-                let code_id = cw.store(key, &path)?;
+                let code_id = self.store_contract(key, chain_name, &path, tx_options)?;
 
                 let id = path
                     .file_stem()
                     .and_then(|stem| stem.to_str())
                     .ok_or(Error::Misc(String::from("failed to format file path")))?;
-                local_chain.contract_codes.insert(id.to_string(), code_id);
+                self.get_mut_chain(chain_name)
+                    .contract_codes
+                    .insert(id.to_string(), code_id);
 
                 Ok(())
             })
@@ -128,6 +161,7 @@ impl TestContext {
         chain_name: &str,
         path: &str,
         local_cache_path: &str,
+        tx_options: &TxOptions,
     ) -> Result<(), Error> {
         if fs::metadata(path).is_ok_and(|m| m.is_dir()) {
             info!("Path {} exists, deploying contracts...", path);
@@ -177,11 +211,10 @@ impl TestContext {
             let path = entry.path();
             if path.extension().and_then(|e| e.to_str()) == Some(WASM_EXTENSION) {
                 let abs_path = path.canonicalize().unwrap();
-                let mut cw = CosmWasm::new(&local_chain.rb);
                 let id = abs_path.file_stem().unwrap().to_str().unwrap();
 
                 // To avoid storing multiple times during the same execution
-                if local_chain.contract_codes.contains_key(id) {
+                if self.get_chain(chain_name).contract_codes.contains_key(id) {
                     info!(
                         "Contract {} already deployed on chain {}, skipping...",
                         id, chain_name
@@ -189,16 +222,122 @@ impl TestContext {
                     continue;
                 }
 
-                let code_id = cw.store(key, abs_path.as_path()).unwrap();
+                let code_id = self
+                    .store_contract(key, chain_name, abs_path.as_path(), tx_options)
+                    .unwrap();
 
-                local_chain.contract_codes.insert(id.to_string(), code_id);
+                self.get_mut_chain(chain_name)
+                    .contract_codes
+                    .insert(id.to_string(), code_id);
             }
         }
 
-        let contract_codes = serde_json::to_string(&local_chain.contract_codes).unwrap();
+        let contract_codes =
+            serde_json::to_string(&self.get_chain(chain_name).contract_codes).unwrap();
         let mut file = File::create(session_cache_path).unwrap();
         file.write_all(contract_codes.as_bytes()).unwrap();
 
         Ok(())
     }
+
+    /// Stores a contract, returning its code ID. The artifact is copied to the chain,
+    /// and stored with a `tx wasm store`.
+    fn store_contract(
+        &self,
+        key: &str,
+        chain_name: &str,
+        path: &Path,
+        tx_options: &TxOptions,
+    ) -> Result<u64, Error> {
+        let local_chain = self.get_chain(chain_name);
+        local_chain.rb.upload_file(path, true)?.send()?.text()?;
+
+        let file_name = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .ok_or(Error::Misc(String::from("failed to format file path")))?;
+        let chain_id = &local_chain.rb.chain_id;
+
+        let receipt = local_chain.rb.tx(
+            &format!(
+                "tx wasm store /var/cosmos-chain/{chain_id}/{file_name} --from {key}{}",
+                local_chain.tx_flags(tx_options, &STORE_TX_OPTIONS)?
+            ),
+            true,
+        )?;
+
+        // The code ID is only available from the events of the tx
+        let receipt = self.query_tx_receipt(
+            chain_name,
+            receipt
+                .get("txhash")
+                .and_then(Value::as_str)
+                .ok_or(Error::TxMissingLogs)?,
+        )?;
+
+        receipt
+            .assert_event_emitted("store_code", &[])?
+            .attr("code_id")
+            .and_then(|id| id.parse().ok())
+            .ok_or(Error::MissingContextVariable(String::from("code_id")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::{
+            testing::{mock_context_builder, MockLocalIc},
+            NEUTRON_CHAIN_ID,
+        },
+        *,
+    };
+    use serde_json::json;
+    use std::env;
+
+    #[test]
+    fn test_store_contract_records_code_id_from_store_tx() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_upload("{}");
+        mock.on_tx("tx wasm store", json!({ "txhash": "ABC" }).to_string());
+        mock.on_query(
+            "tx ABC",
+            json!({
+                "txhash": "ABC",
+                "code": 0,
+                "raw_log": "",
+                "events": [{
+                    "type": "store_code",
+                    "attributes": [{ "key": "code_id", "value": "12" }],
+                }],
+            })
+            .to_string(),
+        );
+
+        let file_name = format!("localic_utils_{}_store.wasm", std::process::id());
+        let path = env::temp_dir().join(&file_name);
+        fs::write(&path, b"\0asm").unwrap();
+
+        let ctx = mock_context_builder(&mock).build().unwrap();
+        let code_id = ctx
+            .store_contract(DEFAULT_KEY, NEUTRON_CHAIN_NAME, &path, &TxOptions::EMPTY)
+            .unwrap();
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(code_id, 12);
+
+        let store_cmd = mock
+            .requests()
+            .into_iter()
+            .find_map(|req| {
+                req.cmd()
+                    .filter(|cmd| cmd.starts_with("tx wasm store"))
+                    .map(ToOwned::to_owned)
+            })
+            .unwrap();
+
+        assert!(store_cmd.contains(&format!("/var/cosmos-chain/{NEUTRON_CHAIN_ID}/{file_name}")));
+        assert!(store_cmd.contains("--gas=auto"));
+    }
 }
@@ -1,4 +1,8 @@
+#[cfg(feature = "async")]
+pub mod async_context;
+pub mod balances;
 pub mod fs;
 pub mod queries;
 pub mod setup;
+pub mod state;
 pub mod test_context;
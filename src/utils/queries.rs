@@ -1,6 +1,9 @@
 use crate::{
-    types::ibc::{get_prefixed_denom, parse_denom_trace},
-    TRANSFER_PORT,
+    types::{
+        ibc::{get_prefixed_denom, parse_denom_trace, ChannelInfo, DenomTrace},
+        tx::TxReceipt,
+    },
+    CCV_CONSUMER_PORT, CCV_PROVIDER_PORT, TRANSFER_PORT,
 };
 
 use super::{
@@ -9,36 +12,94 @@ use super::{
         NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, PAIR_NAME, PRICE_ORACLE_NAME, STABLE_PAIR_NAME,
         TX_HASH_QUERY_PAUSE_SEC, TX_HASH_QUERY_RETRIES,
     },
+    setup::ibc::query_json,
     test_context::{LocalChain, TestContext},
 };
+use cosmwasm_std::Coin;
 use localic_std::{modules::cosmwasm::CosmWasm, transactions::ChainRequestBuilder};
 use serde_json::Value;
-use std::{path::PathBuf, thread, time::Duration};
-
-pub enum QueryType {
-    TransferChannel,
-    Connection,
-    CCVChannel,
-    IBCDenom,
-    AdminAddr,
-    NativeDenom,
-    ChainPrefix,
-    RequestBuilder,
-    BuiltContractAddress,
-    CodeInfo,
-    Contract,
-    AuctionsManager,
-    PriceOracle,
-    Auction,
-    TokenfactoryDenom,
-    Factory,
-    AstroPool,
-    OsmoPool,
-}
-
-pub struct TestContextQuery<'a> {
+use std::{marker::PhantomData, path::PathBuf, thread, time::Duration};
+
+/// A query against a TestContext producing a value of a specific type.
+/// Implemented by the marker types passed to TestContextQuery, so that
+/// finalizing a query with a getter of the wrong type fails to compile.
+pub trait ContextQuery<'a>: Sized {
+    type Output;
+
+    /// The name of the query, used when reporting missing values.
+    const NAME: &'static str;
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<Self::Output, Error>;
+}
+
+/// Queries the channel from `src` to `dest` on the `port` of `src`.
+pub struct ChannelQuery;
+
+/// Queries the transfer channel ID from `src` to `dest`.
+pub struct TransferChannelQuery;
+
+/// Queries the connection ID from `src` to `dest`.
+pub struct ConnectionQuery;
+
+/// Queries the CCV channel ID from `src` to `dest`.
+pub struct CcvChannelQuery;
+
+/// Queries the IBC denom on `dest` of `base_denom` native to `src`.
+pub struct IbcDenomQuery;
+
+/// Queries the path and base denom of an IBC `denom` from the ibc-transfer module of `src`.
+pub struct DenomTraceQuery;
+
+/// Queries the balance of `denom` held by `addr` on `src`.
+pub struct BalanceQuery;
+
+/// Queries every balance held by `addr` on `src`.
+pub struct AllBalancesQuery;
+
+/// Queries the admin address of `src`.
+pub struct AdminAddrQuery;
+
+/// Queries the native denom of `src`.
+pub struct NativeDenomQuery;
+
+/// Queries the bech32 prefix of `src`.
+pub struct ChainPrefixQuery;
+
+/// Queries the request builder of a chain.
+pub struct RequestBuilderQuery;
+
+/// Queries the instantiate2 address of `contract` for a `creator` and salt.
+pub struct BuiltContractAddressQuery;
+
+/// Queries the on-chain code info of `contract`.
+pub struct CodeInfoQuery;
+
+/// Queries an uploaded, but not necessarily instantiated, `contract`.
+pub struct ContractQuery;
+
+/// Queries the deployed valence auctions manager.
+pub struct AuctionsManagerQuery;
+
+/// Queries the deployed valence price oracle.
+pub struct PriceOracleQuery;
+
+/// Queries the valence auction for an `offer_asset` and `ask_asset`.
+pub struct AuctionQuery;
+
+/// Queries the tokenfactory denom of a `subdenom` made by a `creator`.
+pub struct TokenfactoryDenomQuery;
+
+/// Queries the deployed astroport factory.
+pub struct FactoryQuery;
+
+/// Queries the astroport pool for a pair of `denoms`.
+pub struct AstroPoolQuery;
+
+/// Queries the ID of the osmosis pool for a pair of `denoms`.
+pub struct OsmoPoolQuery;
+
+pub struct TestContextQuery<'a, Q> {
     context: &'a TestContext,
-    query_type: QueryType,
     src_chain: Option<String>,
     dest_chain: Option<String>,
     contract_name: Option<String>,
@@ -50,17 +111,23 @@ pub struct TestContextQuery<'a> {
 
     base_denom: Option<String>,
     subdenom: Option<String>,
+    denom: Option<String>,
+
+    port: Option<String>,
+
+    addr: Option<String>,
 
     // build-contract-address query args
     creator_address: Option<String>,
     salt_hex_encoded: Option<String>,
+
+    query: PhantomData<Q>,
 }
 
-impl<'a> TestContextQuery<'a> {
-    pub fn new(context: &'a TestContext, query_type: QueryType) -> Self {
+impl<'a, Q: ContextQuery<'a>> TestContextQuery<'a, Q> {
+    pub fn new(context: &'a TestContext) -> Self {
         Self {
             context,
-            query_type,
             src_chain: Some(NEUTRON_CHAIN_NAME.to_owned()),
             dest_chain: None,
             contract_name: None,
@@ -68,9 +135,13 @@ impl<'a> TestContextQuery<'a> {
             ask_asset: None,
             base_denom: None,
             subdenom: None,
+            denom: None,
             denoms: None,
+            port: None,
+            addr: None,
             creator_address: None,
             salt_hex_encoded: None,
+            query: PhantomData,
         }
     }
 
@@ -114,155 +185,105 @@ impl<'a> TestContextQuery<'a> {
         self
     }
 
-    pub fn creator(mut self, creator_addr: &str) -> Self {
-        self.creator_address = Some(creator_addr.to_owned());
+    pub fn denom(mut self, denom: &str) -> Self {
+        self.denom = Some(denom.to_owned());
         self
     }
 
-    pub fn salt_hex_encoded(mut self, salt_hex_encoded: &str) -> Self {
-        self.salt_hex_encoded = Some(salt_hex_encoded.to_owned());
+    pub fn port(mut self, port: &str) -> Self {
+        self.port = Some(port.to_owned());
         self
     }
 
-    pub fn get(self) -> String {
-        // None cases explicitly enumerated here to ensure compilation-time
-        // checking of query inclusion in some get_x method
-        match self.query_type {
-            QueryType::TransferChannel => self.get_transfer_channel().map(ToOwned::to_owned),
-            QueryType::Connection => self.get_connection_id().map(ToOwned::to_owned),
-            QueryType::CCVChannel => self.get_ccv_channel().map(ToOwned::to_owned),
-            QueryType::IBCDenom => self.get_ibc_denom(),
-            QueryType::AdminAddr => self.get_admin_addr().map(ToOwned::to_owned),
-            QueryType::NativeDenom => self.get_native_denom().map(ToOwned::to_owned),
-            QueryType::ChainPrefix => self.get_chain_prefix().map(ToOwned::to_owned),
-            QueryType::BuiltContractAddress => self.get_built_contract_address(),
-            QueryType::TokenfactoryDenom => self.get_tokenfactory_denom(),
-            QueryType::CodeInfo
-            | QueryType::OsmoPool
-            | QueryType::AuctionsManager
-            | QueryType::AstroPool
-            | QueryType::Auction
-            | QueryType::Contract
-            | QueryType::Factory
-            | QueryType::RequestBuilder
-            | QueryType::PriceOracle => None,
-        }
-        .unwrap()
+    pub fn addr(mut self, addr: &str) -> Self {
+        self.addr = Some(addr.to_owned());
+        self
     }
 
-    pub fn get_cw(self) -> CosmWasm<'a> {
-        match self.query_type {
-            QueryType::Contract => self.get_contract(),
-            QueryType::Auction => self.get_auction(),
-            QueryType::Factory | QueryType::PriceOracle | QueryType::AuctionsManager => {
-                self.get_deployed_contract()
-            }
-            QueryType::AstroPool => self.get_astro_pool(),
-            QueryType::TransferChannel
-            | QueryType::Connection
-            | QueryType::CCVChannel
-            | QueryType::IBCDenom
-            | QueryType::AdminAddr
-            | QueryType::NativeDenom
-            | QueryType::ChainPrefix
-            | QueryType::BuiltContractAddress
-            | QueryType::TokenfactoryDenom
-            | QueryType::OsmoPool
-            | QueryType::RequestBuilder
-            | QueryType::CodeInfo => None,
-        }
-        .unwrap()
+    pub fn creator(mut self, creator_addr: &str) -> Self {
+        self.creator_address = Some(creator_addr.to_owned());
+        self
     }
 
-    pub fn get_value(self) -> Value {
-        match self.query_type {
-            QueryType::CodeInfo => self.get_code_info(),
-            QueryType::TransferChannel
-            | QueryType::Connection
-            | QueryType::CCVChannel
-            | QueryType::IBCDenom
-            | QueryType::AdminAddr
-            | QueryType::NativeDenom
-            | QueryType::ChainPrefix
-            | QueryType::BuiltContractAddress
-            | QueryType::TokenfactoryDenom
-            | QueryType::OsmoPool
-            | QueryType::AuctionsManager
-            | QueryType::AstroPool
-            | QueryType::Auction
-            | QueryType::Contract
-            | QueryType::Factory
-            | QueryType::RequestBuilder
-            | QueryType::PriceOracle => None,
-        }
-        .unwrap()
+    pub fn salt_hex_encoded(mut self, salt_hex_encoded: &str) -> Self {
+        self.salt_hex_encoded = Some(salt_hex_encoded.to_owned());
+        self
     }
 
-    pub fn get_u64(self) -> u64 {
-        match self.query_type {
-            QueryType::OsmoPool => self.get_osmo_pool(),
-            QueryType::TransferChannel
-            | QueryType::Connection
-            | QueryType::CCVChannel
-            | QueryType::IBCDenom
-            | QueryType::AdminAddr
-            | QueryType::NativeDenom
-            | QueryType::ChainPrefix
-            | QueryType::BuiltContractAddress
-            | QueryType::TokenfactoryDenom
-            | QueryType::AuctionsManager
-            | QueryType::AstroPool
-            | QueryType::Auction
-            | QueryType::Contract
-            | QueryType::Factory
-            | QueryType::RequestBuilder
-            | QueryType::PriceOracle
-            | QueryType::CodeInfo => None,
-        }
-        .unwrap()
+    pub fn get(self) -> Q::Output {
+        self.try_get().unwrap_or_else(|e| panic!("{e}"))
     }
 
-    pub fn get_all(self) -> Vec<String> {
-        match self.query_type {
-            QueryType::TransferChannel => self.get_all_transfer_channels(),
-            QueryType::Connection => self.get_all_connections(),
-            _ => vec![],
-        }
+    pub fn try_get(self) -> Result<Q::Output, Error> {
+        Q::query(&self)
+    }
+
+    /// Describes the query and its arguments for error reporting.
+    fn missing_variable(&self) -> Error {
+        let args = [
+            ("src", self.src_chain.clone()),
+            ("dest", self.dest_chain.clone()),
+            ("contract", self.contract_name.clone()),
+            (
+                "denoms",
+                self.denoms
+                    .as_ref()
+                    .map(|(denom_a, denom_b)| format!("{denom_a}, {denom_b}")),
+            ),
+            ("offer_asset", self.offer_asset.clone()),
+            ("ask_asset", self.ask_asset.clone()),
+            ("base_denom", self.base_denom.clone()),
+            ("subdenom", self.subdenom.clone()),
+            ("denom", self.denom.clone()),
+            ("port", self.port.clone()),
+            ("addr", self.addr.clone()),
+            ("creator", self.creator_address.clone()),
+            ("salt", self.salt_hex_encoded.clone()),
+        ]
         .into_iter()
-        .map(ToOwned::to_owned)
+        .filter_map(|(name, arg)| arg.map(|arg| format!("{name}: {arg}")))
         .collect::<Vec<_>>()
-    }
+        .join(", ");
 
-    pub fn get_request_builder(mut self, chain: &str) -> &'a ChainRequestBuilder {
-        self.src_chain = Some(chain.to_string());
-        let rb = match self.query_type {
-            QueryType::RequestBuilder => self.get_rb(),
-            _ => None,
-        };
-        rb.unwrap()
+        Error::MissingContextVariable(format!("{} {{ {args} }}", Q::NAME))
     }
 
-    fn get_transfer_channel(&self) -> Option<&str> {
-        self.context
-            .transfer_channel_ids
-            .get(&(self.src_chain.clone()?, self.dest_chain.clone()?))
-            .map(|s| s.as_str())
+    fn get_channel_on_port(&self, port: &str) -> Option<&ChannelInfo> {
+        self.context.channels.get(&(
+            self.src_chain.clone()?,
+            self.dest_chain.clone()?,
+            port.to_owned(),
+        ))
     }
 
-    fn get_all_transfer_channels(&self) -> Vec<&str> {
+    fn get_all_channels_on_port(&self, port: Option<&str>) -> Vec<&ChannelInfo> {
         self.src_chain
             .as_ref()
             .map(|src| {
                 self.context
-                    .transfer_channel_ids
+                    .channels
                     .iter()
-                    .filter(|((s, _), _)| s == src)
-                    .map(|(_, v)| v.as_str())
+                    .filter(|((s, _, p), _)| {
+                        s == src && (port.is_none() || port == Some(p.as_str()))
+                    })
+                    .map(|(_, channel)| channel)
                     .collect::<Vec<_>>()
             })
             .unwrap_or_default()
     }
 
+    fn get_transfer_channel(&self) -> Option<&str> {
+        self.get_channel_on_port(TRANSFER_PORT)
+            .map(|channel| channel.channel_id.as_str())
+    }
+
+    fn get_all_transfer_channels(&self) -> Vec<&str> {
+        self.get_all_channels_on_port(Some(TRANSFER_PORT))
+            .into_iter()
+            .map(|channel| channel.channel_id.as_str())
+            .collect()
+    }
+
     fn get_connection_id(&self) -> Option<&str> {
         self.context
             .connection_ids
@@ -285,10 +306,9 @@ impl<'a> TestContextQuery<'a> {
     }
 
     fn get_ccv_channel(&self) -> Option<&str> {
-        self.context
-            .ccv_channel_ids
-            .get(&(self.src_chain.clone()?, self.dest_chain.clone()?))
-            .map(|s| s.as_str())
+        self.get_channel_on_port(CCV_PROVIDER_PORT)
+            .or_else(|| self.get_channel_on_port(CCV_CONSUMER_PORT))
+            .map(|channel| channel.channel_id.as_str())
     }
 
     fn get_ibc_denom(&self) -> Option<String> {
@@ -300,7 +320,8 @@ impl<'a> TestContextQuery<'a> {
             .get_transfer_channels()
             .src(dest_chain)
             .dest(src_chain)
-            .get();
+            .try_get()
+            .ok()?;
 
         let prefixed_denom = get_prefixed_denom(
             TRANSFER_PORT.to_string(),
@@ -314,6 +335,50 @@ impl<'a> TestContextQuery<'a> {
         Some(ibc_denom)
     }
 
+    fn get_denom_trace(&self) -> Option<DenomTrace> {
+        let chain = self.context.chains.get(self.src_chain.as_deref()?)?;
+        let hash = self.denom.as_deref()?.trim_start_matches("ibc/");
+
+        let resp = chain.rb.query(
+            &format!("q ibc-transfer denom-trace {hash} --output=json"),
+            true,
+        );
+
+        let mut trace_info: Value = serde_json::from_str(resp["text"].as_str()?).ok()?;
+        serde_json::from_value(trace_info["denom_trace"].take()).ok()
+    }
+
+    fn get_all_balances(&self) -> Result<Vec<Coin>, Error> {
+        let chain = self
+            .src_chain
+            .as_deref()
+            .and_then(|src| self.context.chains.get(src))
+            .ok_or_else(|| self.missing_variable())?;
+        let addr = self
+            .addr
+            .as_deref()
+            .ok_or_else(|| self.missing_variable())?;
+
+        let mut balances = query_json(&chain.rb, &format!("q bank balances {addr}"))?;
+
+        Ok(serde_json::from_value(balances["balances"].take())?)
+    }
+
+    fn get_balance(&self) -> Result<u128, Error> {
+        let denom = self
+            .denom
+            .as_deref()
+            .ok_or_else(|| self.missing_variable())?;
+
+        // Denoms that are not held are omitted from the account's balances
+        Ok(self
+            .get_all_balances()?
+            .into_iter()
+            .find(|coin| coin.denom == denom)
+            .map(|coin| coin.amount.u128())
+            .unwrap_or_default())
+    }
+
     fn get_admin_addr(&self) -> Option<&str> {
         let src = self.src_chain.as_deref()?;
 
@@ -338,7 +403,8 @@ impl<'a> TestContextQuery<'a> {
             .get_contract()
             .src(self.src_chain.as_deref()?)
             .contract(self.contract_name.as_ref()?)
-            .get_cw();
+            .try_get_cw()
+            .ok()?;
         let code_id = contract.code_id?;
         let chain = self.context.chains.get(self.src_chain.as_deref()?)?;
 
@@ -378,7 +444,7 @@ impl<'a> TestContextQuery<'a> {
     }
 
     fn get_contract(&self) -> Option<CosmWasm<'a>> {
-        let chain: &LocalChain = self.context.get_chain(self.src_chain.as_deref()?);
+        let chain: &LocalChain = self.context.chains.get(self.src_chain.as_deref()?)?;
         let name = self.contract_name.as_deref()?;
 
         let code_id = chain.contract_codes.get(name)?;
@@ -393,7 +459,7 @@ impl<'a> TestContextQuery<'a> {
     }
 
     fn get_deployed_contract(&self) -> Option<CosmWasm<'a>> {
-        let chain = self.context.get_chain(self.src_chain.as_deref()?);
+        let chain = self.context.chains.get(self.src_chain.as_deref()?)?;
         let name = self.contract_name.as_deref()?;
 
         let code_id = chain.contract_codes.get(name)?;
@@ -413,7 +479,8 @@ impl<'a> TestContextQuery<'a> {
             .context
             .get_auctions_manager()
             .src(self.src_chain.as_deref()?)
-            .get_cw();
+            .try_get_cw()
+            .ok()?;
         let denoms = (self.offer_asset.as_deref()?, self.ask_asset.as_deref()?);
 
         let resp = auction_manager.query(
@@ -422,7 +489,7 @@ impl<'a> TestContextQuery<'a> {
                     "pair": denoms,
                 }}
             ))
-            .unwrap(),
+            .ok()?,
         );
 
         let mut cw = self
@@ -430,7 +497,8 @@ impl<'a> TestContextQuery<'a> {
             .get_contract()
             .contract(PAIR_NAME)
             .src(self.src_chain.as_deref()?)
-            .get_cw();
+            .try_get_cw()
+            .ok()?;
         cw.contract_addr = Some(resp["data"].as_str()?.to_owned());
 
         Some(cw)
@@ -442,7 +510,8 @@ impl<'a> TestContextQuery<'a> {
             .context
             .get_factory()
             .src(self.src_chain.as_deref()?)
-            .get_cw();
+            .try_get_cw()
+            .ok()?;
 
         let pair_info = factory.query_value(&serde_json::json!(
             {
@@ -466,14 +535,12 @@ impl<'a> TestContextQuery<'a> {
         let addr = pair_info
             .get("data")
             .and_then(|data| data.get("contract_addr"))
-            .and_then(|addr| addr.as_str())
-            .unwrap();
+            .and_then(|addr| addr.as_str())?;
         let kind = pair_info
             .get("data")
-            .and_then(|data| data.get("pair_type"))
-            .unwrap();
+            .and_then(|data| data.get("pair_type"))?;
 
-        let chain = self.context.get_chain(self.src_chain.as_deref()?);
+        let chain = self.context.chains.get(self.src_chain.as_deref()?)?;
 
         if kind.get("xyk").is_some() {
             let contract = self
@@ -481,7 +548,8 @@ impl<'a> TestContextQuery<'a> {
                 .get_contract()
                 .contract(PAIR_NAME)
                 .src(self.src_chain.as_deref()?)
-                .get_cw();
+                .try_get_cw()
+                .ok()?;
 
             return Some(CosmWasm::new_from_existing(
                 &chain.rb,
@@ -496,7 +564,8 @@ impl<'a> TestContextQuery<'a> {
             .get_contract()
             .contract(STABLE_PAIR_NAME)
             .src(self.src_chain.as_deref()?)
-            .get_cw();
+            .try_get_cw()
+            .ok()?;
 
         Some(CosmWasm::new_from_existing(
             &chain.rb,
@@ -509,17 +578,17 @@ impl<'a> TestContextQuery<'a> {
     fn get_osmo_pool(&self) -> Option<u64> {
         // Do not use get_chain here, since we only want to support osmosis pools on osmosis
         let (denom_a, denom_b) = self.denoms.as_ref()?;
-        let osmosis = self.context.get_chain(OSMOSIS_CHAIN_NAME);
+        let osmosis = self.context.chains.get(OSMOSIS_CHAIN_NAME)?;
 
         let res = osmosis.rb.query(
             &format!("q poolmanager list-pools-by-denom {denom_a} --output=json"),
             true,
         );
 
-        let res_text = res.get("text").and_then(|v| v.as_str()).unwrap();
+        let res_text = res.get("text").and_then(|v| v.as_str())?;
         let res_value: Value = serde_json::from_str(res_text).ok()?;
 
-        let pools_value = res_value.get("pools").unwrap();
+        let pools_value = res_value.get("pools")?;
         let pool = pools_value
             .as_array()
             .and_then(|pools| {
@@ -537,10 +606,9 @@ impl<'a> TestContextQuery<'a> {
                 })
             })
             .and_then(|pool| pool.get("id"))
-            .and_then(|id_str| id_str.as_str())
-            .unwrap();
+            .and_then(|id_str| id_str.as_str())?;
 
-        Some(pool.parse().unwrap())
+        pool.parse().ok()
     }
 
     fn get_rb(&self) -> Option<&'a ChainRequestBuilder> {
@@ -552,78 +620,407 @@ impl<'a> TestContextQuery<'a> {
     }
 }
 
+impl<'a, Q: ContextQuery<'a, Output = CosmWasm<'a>>> TestContextQuery<'a, Q> {
+    pub fn get_cw(self) -> CosmWasm<'a> {
+        self.get()
+    }
+
+    pub fn try_get_cw(self) -> Result<CosmWasm<'a>, Error> {
+        self.try_get()
+    }
+}
+
+impl<'a, Q: ContextQuery<'a, Output = Value>> TestContextQuery<'a, Q> {
+    pub fn get_value(self) -> Value {
+        self.get()
+    }
+
+    pub fn try_get_value(self) -> Result<Value, Error> {
+        self.try_get()
+    }
+}
+
+impl<'a, Q: ContextQuery<'a, Output = u128>> TestContextQuery<'a, Q> {
+    pub fn get_u128(self) -> u128 {
+        self.get()
+    }
+
+    pub fn try_get_u128(self) -> Result<u128, Error> {
+        self.try_get()
+    }
+}
+
+impl<'a, Q: ContextQuery<'a, Output = u64>> TestContextQuery<'a, Q> {
+    pub fn get_u64(self) -> u64 {
+        self.get()
+    }
+
+    pub fn try_get_u64(self) -> Result<u64, Error> {
+        self.try_get()
+    }
+}
+
+impl<'a> TestContextQuery<'a, ChannelQuery> {
+    /// Gets all channels from `src`, on `port` if it is set.
+    pub fn get_all(self) -> Vec<ChannelInfo> {
+        self.get_all_channels_on_port(self.port.as_deref())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+}
+
+impl<'a> TestContextQuery<'a, TransferChannelQuery> {
+    pub fn get_all(self) -> Vec<String> {
+        self.get_all_transfer_channels()
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect()
+    }
+}
+
+impl<'a> TestContextQuery<'a, ConnectionQuery> {
+    pub fn get_all(self) -> Vec<String> {
+        self.get_all_connections()
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect()
+    }
+}
+
+impl<'a> TestContextQuery<'a, RequestBuilderQuery> {
+    pub fn get_request_builder(self, chain: &str) -> &'a ChainRequestBuilder {
+        self.src(chain).get()
+    }
+
+    pub fn try_get_request_builder(self, chain: &str) -> Result<&'a ChainRequestBuilder, Error> {
+        self.src(chain).try_get()
+    }
+}
+
+impl<'a> ContextQuery<'a> for ChannelQuery {
+    type Output = ChannelInfo;
+    const NAME: &'static str = "channel";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<ChannelInfo, Error> {
+        q.port
+            .as_deref()
+            .and_then(|port| q.get_channel_on_port(port))
+            .cloned()
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for TransferChannelQuery {
+    type Output = String;
+    const NAME: &'static str = "transfer_channel";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_transfer_channel()
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for ConnectionQuery {
+    type Output = String;
+    const NAME: &'static str = "connection";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_connection_id()
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for CcvChannelQuery {
+    type Output = String;
+    const NAME: &'static str = "ccv_channel";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_ccv_channel()
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for BalanceQuery {
+    type Output = u128;
+    const NAME: &'static str = "balance";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<u128, Error> {
+        q.get_balance()
+    }
+}
+
+impl<'a> ContextQuery<'a> for AllBalancesQuery {
+    type Output = Vec<Coin>;
+    const NAME: &'static str = "all_balances";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<Vec<Coin>, Error> {
+        q.get_all_balances()
+    }
+}
+
+impl<'a> ContextQuery<'a> for IbcDenomQuery {
+    type Output = String;
+    const NAME: &'static str = "ibc_denom";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_ibc_denom().ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for DenomTraceQuery {
+    type Output = DenomTrace;
+    const NAME: &'static str = "denom_trace";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<DenomTrace, Error> {
+        let trace = q.get_denom_trace().ok_or_else(|| q.missing_variable())?;
+
+        // Ensure the chain's trace matches the locally computed denom
+        let denom = q.denom.clone().unwrap_or_default();
+        let trace_denom = trace.ibc_denom();
+
+        if !trace_denom
+            .trim_start_matches("ibc/")
+            .eq_ignore_ascii_case(denom.trim_start_matches("ibc/"))
+        {
+            return Err(Error::DenomTraceMismatch { denom, trace_denom });
+        }
+
+        Ok(trace)
+    }
+}
+
+impl<'a> ContextQuery<'a> for AdminAddrQuery {
+    type Output = String;
+    const NAME: &'static str = "admin_addr";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_admin_addr()
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for NativeDenomQuery {
+    type Output = String;
+    const NAME: &'static str = "native_denom";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_native_denom()
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for ChainPrefixQuery {
+    type Output = String;
+    const NAME: &'static str = "chain_prefix";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_chain_prefix()
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for RequestBuilderQuery {
+    type Output = &'a ChainRequestBuilder;
+    const NAME: &'static str = "request_builder";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<&'a ChainRequestBuilder, Error> {
+        q.get_rb().ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for BuiltContractAddressQuery {
+    type Output = String;
+    const NAME: &'static str = "built_contract_address";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_built_contract_address()
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for CodeInfoQuery {
+    type Output = Value;
+    const NAME: &'static str = "code_info";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<Value, Error> {
+        q.get_code_info().ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for ContractQuery {
+    type Output = CosmWasm<'a>;
+    const NAME: &'static str = "contract";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<CosmWasm<'a>, Error> {
+        q.get_contract().ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for AuctionsManagerQuery {
+    type Output = CosmWasm<'a>;
+    const NAME: &'static str = "auctions_manager";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<CosmWasm<'a>, Error> {
+        q.get_deployed_contract()
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for PriceOracleQuery {
+    type Output = CosmWasm<'a>;
+    const NAME: &'static str = "price_oracle";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<CosmWasm<'a>, Error> {
+        q.get_deployed_contract()
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for AuctionQuery {
+    type Output = CosmWasm<'a>;
+    const NAME: &'static str = "auction";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<CosmWasm<'a>, Error> {
+        q.get_auction().ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for TokenfactoryDenomQuery {
+    type Output = String;
+    const NAME: &'static str = "tokenfactory_denom";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<String, Error> {
+        q.get_tokenfactory_denom()
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for FactoryQuery {
+    type Output = CosmWasm<'a>;
+    const NAME: &'static str = "factory";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<CosmWasm<'a>, Error> {
+        q.get_deployed_contract()
+            .ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for AstroPoolQuery {
+    type Output = CosmWasm<'a>;
+    const NAME: &'static str = "astro_pool";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<CosmWasm<'a>, Error> {
+        q.get_astro_pool().ok_or_else(|| q.missing_variable())
+    }
+}
+
+impl<'a> ContextQuery<'a> for OsmoPoolQuery {
+    type Output = u64;
+    const NAME: &'static str = "osmo_pool";
+
+    fn query(q: &TestContextQuery<'a, Self>) -> Result<u64, Error> {
+        q.get_osmo_pool().ok_or_else(|| q.missing_variable())
+    }
+}
+
 impl TestContext {
-    pub fn get_transfer_channels(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::TransferChannel)
+    /// Gets the channel from `src` to `dest` on a `port` of `src`,
+    /// e.g. `get_channel().src("neutron").dest("gaia").port("icacontroller-...")`.
+    pub fn get_channel(&self) -> TestContextQuery<ChannelQuery> {
+        TestContextQuery::new(self)
+    }
+
+    pub fn get_transfer_channels(&self) -> TestContextQuery<TransferChannelQuery> {
+        TestContextQuery::new(self)
+    }
+
+    pub fn get_connections(&self) -> TestContextQuery<ConnectionQuery> {
+        TestContextQuery::new(self)
+    }
+
+    pub fn get_ccv_channels(&self) -> TestContextQuery<CcvChannelQuery> {
+        TestContextQuery::new(self)
+    }
+
+    pub fn get_ibc_denom(&self) -> TestContextQuery<IbcDenomQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_connections(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::Connection)
+    pub fn get_denom_trace(&self) -> TestContextQuery<DenomTraceQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_ccv_channels(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::CCVChannel)
+    /// Gets the balance of a denom held by an address,
+    /// e.g. `get_balance().src("neutron").addr(addr).denom("untrn")`.
+    pub fn get_balance(&self) -> TestContextQuery<BalanceQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_ibc_denom(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::IBCDenom)
+    pub fn get_all_balances(&self) -> TestContextQuery<AllBalancesQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_admin_addr(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::AdminAddr)
+    pub fn get_admin_addr(&self) -> TestContextQuery<AdminAddrQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_native_denom(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::NativeDenom)
+    pub fn get_native_denom(&self) -> TestContextQuery<NativeDenomQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_chain_prefix(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::ChainPrefix)
+    pub fn get_chain_prefix(&self) -> TestContextQuery<ChainPrefixQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_request_builder(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::RequestBuilder)
+    pub fn get_request_builder(&self) -> TestContextQuery<RequestBuilderQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_code_info(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::CodeInfo)
+    pub fn get_code_info(&self) -> TestContextQuery<CodeInfoQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_built_contract_address(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::BuiltContractAddress)
+    pub fn get_built_contract_address(&self) -> TestContextQuery<BuiltContractAddressQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_contract(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::Contract)
+    pub fn get_contract(&self) -> TestContextQuery<ContractQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_tokenfactory_denom(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::TokenfactoryDenom)
+    pub fn get_tokenfactory_denom(&self) -> TestContextQuery<TokenfactoryDenomQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_price_oracle(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::PriceOracle).contract(PRICE_ORACLE_NAME)
+    pub fn get_price_oracle(&self) -> TestContextQuery<PriceOracleQuery> {
+        TestContextQuery::new(self).contract(PRICE_ORACLE_NAME)
     }
 
-    pub fn get_auction(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::Auction).contract(AUCTION_CONTRACT_NAME)
+    pub fn get_auction(&self) -> TestContextQuery<AuctionQuery> {
+        TestContextQuery::new(self).contract(AUCTION_CONTRACT_NAME)
     }
 
-    pub fn get_factory(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::Factory).contract(FACTORY_NAME)
+    pub fn get_factory(&self) -> TestContextQuery<FactoryQuery> {
+        TestContextQuery::new(self).contract(FACTORY_NAME)
     }
 
-    pub fn get_astro_pool(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::AstroPool)
+    pub fn get_astro_pool(&self) -> TestContextQuery<AstroPoolQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_osmo_pool(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::OsmoPool)
+    pub fn get_osmo_pool(&self) -> TestContextQuery<OsmoPoolQuery> {
+        TestContextQuery::new(self)
     }
 
-    pub fn get_auctions_manager(&self) -> TestContextQuery {
-        TestContextQuery::new(self, QueryType::AuctionsManager)
-            .contract(AUCTIONS_MANAGER_CONTRACT_NAME)
+    pub fn get_auctions_manager(&self) -> TestContextQuery<AuctionsManagerQuery> {
+        TestContextQuery::new(self).contract(AUCTIONS_MANAGER_CONTRACT_NAME)
     }
 
     pub fn get_chain(&self, chain_name: &str) -> &LocalChain {
@@ -634,48 +1031,259 @@ impl TestContext {
         self.chains.get_mut(chain_name).unwrap()
     }
 
-    /// Gets the event log of a transaction as a JSON object,
-    /// or returns an error if it does not exist.
-    pub fn guard_tx_errors(&self, chain_name: &str, hash: &str) -> Result<(), Error> {
+    /// Gets the receipt of a transaction, or returns an error if the
+    /// transaction failed. If log unwrapping is disabled, the receipt
+    /// only contains the transaction hash.
+    pub fn guard_tx_errors(&self, chain_name: &str, hash: &str) -> Result<TxReceipt, Error> {
         if !self.unwrap_logs {
-            return Ok(());
+            return Ok(TxReceipt {
+                hash: hash.to_owned(),
+                ..Default::default()
+            });
         }
 
+        self.query_tx_receipt(chain_name, hash)
+    }
+
+    /// Waits for a transaction to be included in a block, and gets its receipt,
+    /// erroring if its logs report a failure.
+    pub fn query_tx_receipt(&self, chain_name: &str, hash: &str) -> Result<TxReceipt, Error> {
         let chain = self.get_chain(chain_name);
-        let mut logs = None;
+        let mut tx_res = None;
 
         for _ in 0..TX_HASH_QUERY_RETRIES {
             thread::sleep(Duration::from_secs(TX_HASH_QUERY_PAUSE_SEC));
 
-            let mut tx_res = chain.rb.query_tx_hash(hash);
+            let res = chain.rb.query_tx_hash(hash);
 
-            if tx_res.get("raw_log").is_none() {
+            if res.get("raw_log").is_none() {
                 continue;
             }
 
-            logs = Some(tx_res["raw_log"].take());
+            tx_res = Some(res);
 
             break;
         }
 
-        let raw_log = logs.as_ref().and_then(|raw_log| raw_log.as_str()).unwrap();
+        let tx_res = tx_res.ok_or(Error::TxMissingLogs)?;
 
-        if raw_log.is_empty() {
-            return Ok(());
-        }
+        unwrap_tx_response(hash, &tx_res)
+    }
+}
 
-        let logs = serde_json::from_str::<Value>(raw_log).map_err(|_| Error::TxFailed {
+/// Parses a tx query response into a receipt, erroring if its logs report a failure.
+pub(crate) fn unwrap_tx_response(hash: &str, tx_res: &Value) -> Result<TxReceipt, Error> {
+    let receipt = TxReceipt::from_tx_response(hash, tx_res);
+
+    if receipt.raw_log.is_empty() {
+        return Ok(receipt);
+    }
+
+    let logs = serde_json::from_str::<Value>(&receipt.raw_log).map_err(|_| Error::TxFailed {
+        hash: hash.to_owned(),
+        error: receipt.raw_log.clone(),
+    })?;
+
+    if let Some(err) = logs.as_str() {
+        return Err(Error::TxFailed {
             hash: hash.to_owned(),
-            error: raw_log.to_owned(),
-        })?;
+            error: err.to_owned(),
+        });
+    }
 
-        if let Some(err) = logs.as_str() {
-            return Err(Error::TxFailed {
-                hash: hash.to_owned(),
-                error: err.to_owned(),
-            });
-        }
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::testing::{mock_context_builder, MockLocalIc},
+        *,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_guard_tx_errors_ok() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_query(
+            "tx ABC",
+            json!({
+                "txhash": "ABC",
+                "height": "10",
+                "code": 0,
+                "raw_log": "[]",
+                "events": [{
+                    "type": "message",
+                    "attributes": [{ "key": "action", "value": "send" }],
+                }],
+            })
+            .to_string(),
+        );
+
+        let ctx = mock_context_builder(&mock)
+            .with_unwrap_raw_logs(true)
+            .build()
+            .unwrap();
+        let receipt = ctx.guard_tx_errors(NEUTRON_CHAIN_NAME, "ABC").unwrap();
+
+        assert_eq!(receipt.hash, "ABC");
+        assert_eq!(receipt.height, 10);
+        assert!(receipt
+            .assert_event_emitted("message", &[("action", "send")])
+            .is_ok());
+    }
 
-        Ok(())
+    #[test]
+    fn test_guard_tx_errors_failed() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_query(
+            "tx ABC",
+            json!({
+                "txhash": "ABC",
+                "code": 11,
+                "raw_log": "out of gas in location: WriteFlat",
+            })
+            .to_string(),
+        );
+
+        let ctx = mock_context_builder(&mock)
+            .with_unwrap_raw_logs(true)
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            ctx.guard_tx_errors(NEUTRON_CHAIN_NAME, "ABC"),
+            Err(Error::TxFailed { hash, .. }) if hash == "ABC"
+        ));
+    }
+
+    #[test]
+    fn test_guard_tx_errors_without_unwrapping() {
+        let mock = MockLocalIc::start().unwrap();
+        let ctx = mock_context_builder(&mock).build().unwrap();
+
+        let receipt = ctx.guard_tx_errors(NEUTRON_CHAIN_NAME, "ABC").unwrap();
+
+        assert_eq!(receipt.hash, "ABC");
+        assert!(receipt.events.is_empty());
+    }
+
+    #[test]
+    fn test_query_balances() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_query(
+            "bank balances neutron1abc",
+            json!({
+                "balances": [
+                    { "denom": "untrn", "amount": "100" },
+                    { "denom": "uosmo", "amount": "5" },
+                ],
+            })
+            .to_string(),
+        );
+
+        let ctx = mock_context_builder(&mock).build().unwrap();
+
+        assert_eq!(
+            ctx.get_all_balances().addr("neutron1abc").get(),
+            vec![Coin::new(100, "untrn"), Coin::new(5, "uosmo")]
+        );
+        assert_eq!(
+            ctx.get_balance().addr("neutron1abc").denom("untrn").get(),
+            100
+        );
+        assert_eq!(
+            ctx.get_balance().addr("neutron1abc").denom("uatom").get(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_query_denom_trace_ignores_hash_case() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_query(
+            "denom-trace",
+            json!({
+                "denom_trace": { "path": "transfer/channel-0", "base_denom": "uatom" },
+            })
+            .to_string(),
+        );
+
+        let ctx = mock_context_builder(&mock).build().unwrap();
+        let trace = ctx
+            .get_denom_trace()
+            .denom("ibc/27394fb092d2eccd56123c74f36e4c1f926001ceada9ca97ea622b25f41e5eb2")
+            .get();
+
+        assert_eq!(trace.base_denom, "uatom");
+        assert!(ctx
+            .get_denom_trace()
+            .denom("ibc/0000000000000000000000000000000000000000000000000000000000000000")
+            .try_get()
+            .is_err());
+    }
+
+    #[test]
+    fn test_query_osmo_pool() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_query(
+            "poolmanager list-pools-by-denom uosmo",
+            json!({
+                "pools": [{
+                    "id": "1",
+                    "pool_assets": [
+                        { "token": { "denom": "uosmo", "amount": "100" } },
+                        { "token": { "denom": "untrn", "amount": "100" } },
+                    ],
+                }],
+            })
+            .to_string(),
+        );
+
+        let ctx = mock_context_builder(&mock).build().unwrap();
+
+        assert_eq!(
+            ctx.get_osmo_pool()
+                .denoms(String::from("uosmo"), String::from("untrn"))
+                .get_u64(),
+            1
+        );
+        assert!(ctx
+            .get_osmo_pool()
+            .denoms(String::from("uosmo"), String::from("uatom"))
+            .try_get()
+            .is_err());
+    }
+
+    #[test]
+    fn test_query_channels() {
+        let mock = MockLocalIc::start().unwrap();
+        let ctx = mock_context_builder(&mock)
+            .with_transfer_channel_id(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, "channel-0")
+            .with_connection_id(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, "connection-0")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            ctx.get_transfer_channels()
+                .src(NEUTRON_CHAIN_NAME)
+                .dest(OSMOSIS_CHAIN_NAME)
+                .get(),
+            "channel-0"
+        );
+        assert_eq!(
+            ctx.get_connections()
+                .src(NEUTRON_CHAIN_NAME)
+                .dest(OSMOSIS_CHAIN_NAME)
+                .get(),
+            "connection-0"
+        );
+        assert!(matches!(
+            ctx.get_transfer_channels()
+                .src(OSMOSIS_CHAIN_NAME)
+                .dest(NEUTRON_CHAIN_NAME)
+                .try_get(),
+            Err(Error::MissingContextVariable(_))
+        ));
     }
 }
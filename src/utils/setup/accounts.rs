@@ -0,0 +1,153 @@
+use super::super::{
+    super::{error::Error, types::tx::TxReceipt, ADMIN_KEY},
+    test_context::TestContext,
+};
+use serde_json::Value;
+
+/// The amount of the native denom sent to accounts made with `create_account`.
+pub const TEST_ACCOUNT_FUNDS: u128 = 1_000_000_000;
+
+/// A key in a chain's keyring, which may be passed to any builder's `with_key`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestAccount {
+    pub chain_name: String,
+    /// The name of the key in the keyring
+    pub name: String,
+    pub address: String,
+    /// The mnemonic of the key, if it was created by the context
+    pub mnemonic: Option<String>,
+}
+
+impl TestAccount {
+    /// Gets the name of the key, for use with `with_key`.
+    pub fn key(&self) -> &str {
+        &self.name
+    }
+}
+
+impl TestContext {
+    /// Adds a new key to a chain's keyring, and funds it with `TEST_ACCOUNT_FUNDS`
+    /// of the chain's native denom from the admin key.
+    pub fn create_account(&mut self, chain_name: &str, name: &str) -> Result<TestAccount, Error> {
+        let chain = self.get_chain(chain_name);
+        let cmd = format!("keys add {name} --keyring-backend=test --output=json");
+
+        let resp = chain.rb.bin(&cmd, true);
+
+        // Keys are printed to stderr by some SDK versions, so take the last JSON line
+        let key: Value = resp["text"]
+            .as_str()
+            .and_then(|text| {
+                text.lines()
+                    .rev()
+                    .find_map(|line| serde_json::from_str(line).ok())
+            })
+            .ok_or(Error::ContainerCmd(cmd))?;
+        let address = key["address"]
+            .as_str()
+            .ok_or(Error::MissingContextVariable(format!("address::{name}")))?;
+
+        let account = TestAccount {
+            chain_name: chain_name.to_owned(),
+            name: name.to_owned(),
+            address: with_prefix(address, &chain.chain_prefix)?,
+            mnemonic: key["mnemonic"].as_str().map(ToOwned::to_owned),
+        };
+        let native_denom = chain.native_denom.clone();
+
+        self.get_mut_chain(chain_name)
+            .key_addrs
+            .insert(account.name.clone(), account.address.clone());
+        self.fund_account(
+            chain_name,
+            &account.address,
+            TEST_ACCOUNT_FUNDS,
+            &native_denom,
+        )?;
+
+        Ok(account)
+    }
+
+    /// Recovers a key into a chain's keyring from its mnemonic. The account is not funded.
+    pub fn import_account(
+        &mut self,
+        chain_name: &str,
+        name: &str,
+        mnemonic: &str,
+    ) -> Result<TestAccount, Error> {
+        let chain = self.get_chain(chain_name);
+
+        reqwest::blocking::Client::default()
+            .post(&chain.rb.api)
+            .json(&serde_json::json!({
+                "chain_id": chain.rb.chain_id,
+                "action": "recover-key",
+                "cmd": format!("keyname={name};mnemonic={mnemonic}"),
+            }))
+            .send()?
+            .error_for_status()?;
+
+        // The key may have replaced one whose address was cached
+        let address = self.query_key_address(chain_name, name)?;
+        self.get_mut_chain(chain_name)
+            .key_addrs
+            .insert(name.to_owned(), address.clone());
+
+        Ok(TestAccount {
+            chain_name: chain_name.to_owned(),
+            name: name.to_owned(),
+            address,
+            mnemonic: Some(mnemonic.to_owned()),
+        })
+    }
+
+    /// Gets the address of a key in a chain's keyring, encoded with the chain's prefix.
+    /// Addresses of keys created or imported by the context are not queried.
+    pub fn address_of(&self, chain_name: &str, key: &str) -> Result<String, Error> {
+        match self.get_chain(chain_name).key_addrs.get(key) {
+            Some(address) => Ok(address.clone()),
+            None => self.query_key_address(chain_name, key),
+        }
+    }
+
+    /// Queries the address of a key from a chain's keyring.
+    fn query_key_address(&self, chain_name: &str, key: &str) -> Result<String, Error> {
+        let chain = self.get_chain(chain_name);
+        let cmd = format!("keys show {key} --address --keyring-backend=test");
+        let resp = chain.rb.bin(&cmd, true);
+        let address = resp["text"]
+            .as_str()
+            .and_then(|text| text.lines().rev().find(|line| !line.trim().is_empty()))
+            .ok_or(Error::ContainerCmd(cmd))?;
+
+        // Missing keys print an error instead of an address
+        with_prefix(address.trim(), &chain.chain_prefix)
+            .map_err(|_| Error::MissingContextVariable(format!("key_addr::{key}")))
+    }
+
+    /// Sends funds from the admin key to an address.
+    pub fn fund_account(
+        &mut self,
+        chain_name: &str,
+        addr: &str,
+        amount: u128,
+        denom: &str,
+    ) -> Result<TxReceipt, Error> {
+        self.tx_bank_send(
+            ADMIN_KEY,
+            chain_name,
+            addr,
+            denom,
+            amount,
+            &Default::default(),
+        )
+    }
+}
+
+/// Re-encodes a bech32 address with the given prefix, since the keyring
+/// may use the binary's default prefix instead of the chain's.
+fn with_prefix(addr: &str, prefix: &str) -> Result<String, Error> {
+    let (_, data, variant) = bech32::decode(addr)?;
+
+    Ok(bech32::encode(prefix, data, variant)?)
+}
@@ -1,7 +1,9 @@
 use super::super::{
     super::{
-        error::Error, DEFAULT_KEY, FACTORY_NAME, NEUTRON_CHAIN_ADMIN_ADDR, NEUTRON_CHAIN_NAME,
-        PAIR_NAME, STABLE_PAIR_NAME, TOKEN_NAME, TOKEN_REGISTRY_NAME, WHITELIST_NAME,
+        error::Error,
+        types::tx::{Gas, TxOptions, TxReceipt},
+        DEFAULT_KEY, FACTORY_NAME, NEUTRON_CHAIN_NAME, PAIR_NAME, STABLE_PAIR_NAME, TOKEN_NAME,
+        TOKEN_REGISTRY_NAME, WHITELIST_NAME,
     },
     test_context::TestContext,
 };
@@ -12,10 +14,17 @@ use astroport::{
 };
 use cosmwasm_std::Decimal;
 
+/// The default options of astroport txs.
+const ASTROPORT_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Limit(1000000)),
+    ..TxOptions::EMPTY
+};
+
 /// A tx creating a token registry.
 pub struct CreateTokenRegistryTxBuilder<'a> {
     key: Option<&'a str>,
     owner: Option<String>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -26,20 +35,27 @@ impl<'a> CreateTokenRegistryTxBuilder<'a> {
         self
     }
 
+    /// Sets the owner of the registry. Defaults to the address of the signing key.
     pub fn with_owner(&mut self, owner: impl Into<String>) -> &mut Self {
         self.owner = Some(owner.into());
 
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_create_token_registry(
             self.key
                 .ok_or(Error::MissingBuilderParam(String::from("key")))?,
-            self.owner
-                .clone()
-                .ok_or(Error::MissingBuilderParam(String::from("owner")))?,
+            self.owner.clone(),
+            &self.tx_options,
         )
     }
 }
@@ -50,6 +66,7 @@ pub struct CreatePoolTxBuilder<'a> {
     pair_type: PairType,
     denom_a: Option<String>,
     denom_b: Option<String>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -78,8 +95,15 @@ impl<'a> CreatePoolTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_create_pool(
             self.key,
             self.pair_type.clone(),
@@ -89,6 +113,7 @@ impl<'a> CreatePoolTxBuilder<'a> {
             self.denom_b
                 .clone()
                 .ok_or(Error::MissingBuilderParam(String::from("denom_b")))?,
+            &self.tx_options,
         )
     }
 }
@@ -96,7 +121,8 @@ impl<'a> CreatePoolTxBuilder<'a> {
 /// A tx creating an astroport factory.
 pub struct CreateFactoryTxBuilder<'a> {
     key: &'a str,
-    owner: String,
+    owner: Option<String>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -107,16 +133,24 @@ impl<'a> CreateFactoryTxBuilder<'a> {
         self
     }
 
+    /// Sets the owner of the factory. Defaults to the address of the signing key.
     pub fn with_owner(&mut self, owner: impl Into<String>) -> &mut Self {
-        self.owner = owner.into();
+        self.owner = Some(owner.into());
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
 
         self
     }
 
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx
-            .tx_create_factory(self.key, self.owner.clone())
+            .tx_create_factory(self.key, self.owner.clone(), &self.tx_options)
     }
 }
 
@@ -129,6 +163,7 @@ pub struct FundPoolTxBuilder<'a> {
     amt_denom_b: Option<u128>,
     slippage_tolerance: Option<Decimal>,
     liq_token_receiver: Option<&'a str>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -169,14 +204,22 @@ impl<'a> FundPoolTxBuilder<'a> {
         self
     }
 
+    /// Sets the receiver of the LP tokens. Defaults to the address of the signing key.
     pub fn with_liq_token_receiver(&mut self, receiver_addr: &'a str) -> &mut Self {
         self.liq_token_receiver = Some(receiver_addr);
 
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_fund_pool(
             self.key,
             self.denom_a
@@ -193,10 +236,8 @@ impl<'a> FundPoolTxBuilder<'a> {
                 .ok_or(Error::MissingBuilderParam(String::from(
                     "slippage_tolerance",
                 )))?,
-            self.liq_token_receiver
-                .ok_or(Error::MissingBuilderParam(String::from(
-                    "liq_token_receiver",
-                )))?,
+            self.liq_token_receiver,
+            &self.tx_options,
         )
     }
 }
@@ -205,7 +246,8 @@ impl TestContext {
     pub fn build_tx_create_token_registry(&mut self) -> CreateTokenRegistryTxBuilder {
         CreateTokenRegistryTxBuilder {
             key: Some(DEFAULT_KEY),
-            owner: Some(NEUTRON_CHAIN_ADMIN_ADDR.to_owned()),
+            owner: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -214,8 +256,14 @@ impl TestContext {
     fn tx_create_token_registry(
         &mut self,
         key: &str,
-        owner_addr: impl Into<String>,
-    ) -> Result<(), Error> {
+        owner_addr: Option<String>,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let owner_addr = match owner_addr {
+            Some(owner_addr) => owner_addr,
+            None => self.address_of(NEUTRON_CHAIN_NAME, key)?,
+        };
+
         let mut contract_a = self
             .get_contract()
             .src(NEUTRON_CHAIN_NAME)
@@ -224,30 +272,32 @@ impl TestContext {
 
         let contract = contract_a.instantiate(
             key,
-            serde_json::to_string(&native_coin_registry::InstantiateMsg {
-                owner: owner_addr.into(),
-            })?
-            .as_str(),
+            serde_json::to_string(&native_coin_registry::InstantiateMsg { owner: owner_addr })?
+                .as_str(),
             TOKEN_REGISTRY_NAME,
             None,
-            "--gas 1000000",
+            &self
+                .get_chain(NEUTRON_CHAIN_NAME)
+                .tx_flags(tx_options, &ASTROPORT_TX_OPTIONS)?,
         )?;
-        let addr = contract.address;
+
+        let receipt = self.guard_tx_errors(NEUTRON_CHAIN_NAME, contract.tx_hash.as_str())?;
 
         let neutron = self.get_mut_chain(NEUTRON_CHAIN_NAME);
 
         neutron
             .contract_addrs
-            .insert(TOKEN_REGISTRY_NAME.to_owned(), addr.clone());
+            .insert(TOKEN_REGISTRY_NAME.to_owned(), contract.address);
 
-        Ok(())
+        Ok(receipt)
     }
 
     /// Instantiates the astroport factory.
     pub fn build_tx_create_factory(&mut self) -> CreateFactoryTxBuilder {
         CreateFactoryTxBuilder {
             key: DEFAULT_KEY,
-            owner: NEUTRON_CHAIN_ADMIN_ADDR.to_owned(),
+            owner: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -256,9 +306,16 @@ impl TestContext {
     fn tx_create_factory(
         &mut self,
         key: &str,
-        factory_owner: impl Into<String>,
-    ) -> Result<(), Error> {
+        factory_owner: Option<String>,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let factory_owner = match factory_owner {
+            Some(factory_owner) => factory_owner,
+            None => self.address_of(NEUTRON_CHAIN_NAME, key)?,
+        };
+
         let neutron = self.get_chain(NEUTRON_CHAIN_NAME);
+        let flags = neutron.tx_flags(tx_options, &TxOptions::EMPTY)?;
 
         let pair_xyk_code_id =
             neutron
@@ -325,7 +382,7 @@ impl TestContext {
                     },
                 ],
                 token_code_id: *token_code_id,
-                owner: factory_owner.into(),
+                owner: factory_owner,
                 whitelist_code_id: *whitelist_code_id,
                 coin_registry_address: native_registry_addr.clone(),
                 fee_address: None,
@@ -335,16 +392,18 @@ impl TestContext {
             .as_str(),
             FACTORY_NAME,
             None,
-            "",
+            &flags,
         )?;
 
+        let receipt = self.guard_tx_errors(NEUTRON_CHAIN_NAME, contract.tx_hash.as_str())?;
+
         let neutron = self.get_mut_chain(NEUTRON_CHAIN_NAME);
 
         neutron
             .contract_addrs
             .insert(FACTORY_NAME.to_owned(), contract.address);
 
-        Ok(())
+        Ok(receipt)
     }
 
     /// Creates a pool with the specififed denoms.
@@ -354,6 +413,7 @@ impl TestContext {
             pair_type: PairType::Xyk {},
             denom_a: Default::default(),
             denom_b: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -365,7 +425,8 @@ impl TestContext {
         pair_type: PairType,
         denom_a: impl Into<String>,
         denom_b: impl Into<String>,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         // Factory contract instance
         let contract_a = self.get_factory().src(NEUTRON_CHAIN_NAME).get_cw();
 
@@ -385,7 +446,9 @@ impl TestContext {
                 init_params: None,
             })?
             .as_str(),
-            "--gas 1000000",
+            &self
+                .get_chain(NEUTRON_CHAIN_NAME)
+                .tx_flags(tx_options, &ASTROPORT_TX_OPTIONS)?,
         )?;
 
         // Get the address of the createed contract via logs
@@ -393,9 +456,7 @@ impl TestContext {
             "transaction did not produce a tx hash",
         )))?;
 
-        self.guard_tx_errors(NEUTRON_CHAIN_NAME, tx_hash.as_str())?;
-
-        Ok(())
+        self.guard_tx_errors(NEUTRON_CHAIN_NAME, tx_hash.as_str())
     }
 
     /// Provides liquidity for a specific astroport pool.
@@ -408,6 +469,7 @@ impl TestContext {
             amt_denom_b: Default::default(),
             slippage_tolerance: Default::default(),
             liq_token_receiver: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -422,8 +484,14 @@ impl TestContext {
         amt_denom_a: u128,
         amt_denom_b: u128,
         slippage_tolerance: Decimal,
-        liq_token_receiver: impl Into<String>,
-    ) -> Result<(), Error> {
+        liq_token_receiver: Option<&str>,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let liq_token_receiver = match liq_token_receiver {
+            Some(liq_token_receiver) => liq_token_receiver.to_owned(),
+            None => self.address_of(NEUTRON_CHAIN_NAME, key)?,
+        };
+
         // Get the instance from the address
         let pool = self
             .get_astro_pool()
@@ -452,17 +520,19 @@ impl TestContext {
                     ],
                     slippage_tolerance: Some(slippage_tolerance),
                     auto_stake: None,
-                    receiver: Some(liq_token_receiver.into()),
+                    receiver: Some(liq_token_receiver),
                     min_lp_to_receive: None,
                 })?
                 .as_str(),
-                &format!("--amount {amt_denom_a}{denom_a},{amt_denom_b}{denom_b} --gas 1000000"),
+                &format!(
+                    "--amount {amt_denom_a}{denom_a},{amt_denom_b}{denom_b}{}",
+                    self.get_chain(NEUTRON_CHAIN_NAME)
+                        .tx_flags(tx_options, &ASTROPORT_TX_OPTIONS)?
+                ),
             )?
             .tx_hash
             .ok_or(Error::TxMissingLogs)?;
 
-        self.guard_tx_errors(NEUTRON_CHAIN_NAME, tx.as_str())?;
-
-        Ok(())
+        self.guard_tx_errors(NEUTRON_CHAIN_NAME, tx.as_str())
     }
 }
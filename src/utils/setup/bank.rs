@@ -0,0 +1,259 @@
+use super::super::{
+    super::{
+        error::Error,
+        types::tx::{Gas, TxOptions, TxReceipt},
+        DEFAULT_KEY, NEUTRON_CHAIN_NAME,
+    },
+    test_context::TestContext,
+};
+
+/// The default options of bank sends.
+const BANK_SEND_TX_OPTIONS: TxOptions = TxOptions {
+    fee_amount: Some(5000),
+    ..TxOptions::EMPTY
+};
+
+/// The default options of multi-sends, whose gas scales with the number of recipients,
+/// so it is estimated.
+const MULTI_SEND_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Auto),
+    gas_adjustment: Some(1.3),
+    fee_amount: Some(100000),
+    ..TxOptions::EMPTY
+};
+
+/// A tx sending funds between accounts on the same chain.
+pub struct BankSendTxBuilder<'a> {
+    key: &'a str,
+    chain_name: &'a str,
+    recipient: Option<&'a str>,
+    denom: Option<&'a str>,
+    amount: Option<u128>,
+    tx_options: TxOptions,
+    test_ctx: &'a mut TestContext,
+}
+
+impl<'a> BankSendTxBuilder<'a> {
+    pub fn with_key(&mut self, key: &'a str) -> &mut Self {
+        self.key = key;
+
+        self
+    }
+
+    pub fn with_chain_name(&mut self, chain_name: &'a str) -> &mut Self {
+        self.chain_name = chain_name;
+
+        self
+    }
+
+    pub fn with_recipient(&mut self, recipient: &'a str) -> &mut Self {
+        self.recipient = Some(recipient);
+
+        self
+    }
+
+    pub fn with_denom(&mut self, denom: &'a str) -> &mut Self {
+        self.denom = Some(denom);
+
+        self
+    }
+
+    pub fn with_amount(&mut self, amount: u128) -> &mut Self {
+        self.amount = Some(amount);
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
+    /// Sends the built bank send tx.
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
+        self.test_ctx.tx_bank_send(
+            self.key,
+            self.chain_name,
+            self.recipient
+                .ok_or(Error::MissingBuilderParam(String::from("recipient")))?,
+            self.denom
+                .ok_or(Error::MissingBuilderParam(String::from("denom")))?,
+            self.amount
+                .ok_or(Error::MissingBuilderParam(String::from("amount")))?,
+            &self.tx_options,
+        )
+    }
+}
+
+/// A tx sending funds from one account to several accounts on the same chain.
+pub struct MultiSendTxBuilder<'a> {
+    key: &'a str,
+    chain_name: &'a str,
+    recipients: Vec<&'a str>,
+    denom: Option<&'a str>,
+    amount: Option<u128>,
+    split: bool,
+    tx_options: TxOptions,
+    test_ctx: &'a mut TestContext,
+}
+
+impl<'a> MultiSendTxBuilder<'a> {
+    pub fn with_key(&mut self, key: &'a str) -> &mut Self {
+        self.key = key;
+
+        self
+    }
+
+    pub fn with_chain_name(&mut self, chain_name: &'a str) -> &mut Self {
+        self.chain_name = chain_name;
+
+        self
+    }
+
+    /// Adds a recipient of the funds. May be called several times.
+    pub fn with_recipient(&mut self, recipient: &'a str) -> &mut Self {
+        self.recipients.push(recipient);
+
+        self
+    }
+
+    pub fn with_recipients(&mut self, recipients: &[&'a str]) -> &mut Self {
+        self.recipients.extend_from_slice(recipients);
+
+        self
+    }
+
+    pub fn with_denom(&mut self, denom: &'a str) -> &mut Self {
+        self.denom = Some(denom);
+
+        self
+    }
+
+    /// Sets the amount sent to each recipient, or the total amount if the amount is split.
+    pub fn with_amount(&mut self, amount: u128) -> &mut Self {
+        self.amount = Some(amount);
+
+        self
+    }
+
+    /// Splits the amount evenly between the recipients, instead of sending it to each.
+    pub fn with_split(&mut self, split: bool) -> &mut Self {
+        self.split = split;
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
+    /// Sends the built multi-send tx.
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
+        if self.recipients.is_empty() {
+            return Err(Error::MissingBuilderParam(String::from("recipients")));
+        }
+
+        self.test_ctx.tx_multi_send(
+            self.key,
+            self.chain_name,
+            &self.recipients,
+            self.denom
+                .ok_or(Error::MissingBuilderParam(String::from("denom")))?,
+            self.amount
+                .ok_or(Error::MissingBuilderParam(String::from("amount")))?,
+            self.split,
+            &self.tx_options,
+        )
+    }
+}
+
+impl TestContext {
+    /// Creates a builder building a transaction sending funds to an account on the same chain.
+    pub fn build_tx_bank_send(&mut self) -> BankSendTxBuilder {
+        BankSendTxBuilder {
+            key: DEFAULT_KEY,
+            chain_name: NEUTRON_CHAIN_NAME,
+            recipient: Default::default(),
+            denom: Default::default(),
+            amount: Default::default(),
+            tx_options: Default::default(),
+            test_ctx: self,
+        }
+    }
+
+    /// Creates a builder building a transaction sending funds to several accounts on the same chain.
+    pub fn build_tx_multi_send(&mut self) -> MultiSendTxBuilder {
+        MultiSendTxBuilder {
+            key: DEFAULT_KEY,
+            chain_name: NEUTRON_CHAIN_NAME,
+            recipients: Default::default(),
+            denom: Default::default(),
+            amount: Default::default(),
+            split: Default::default(),
+            tx_options: Default::default(),
+            test_ctx: self,
+        }
+    }
+
+    pub(crate) fn tx_bank_send(
+        &mut self,
+        key: &str,
+        chain_name: &str,
+        recipient: &str,
+        denom: &str,
+        amount: u128,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let chain = self.get_chain(chain_name);
+        let flags = chain.tx_flags(tx_options, &BANK_SEND_TX_OPTIONS)?;
+
+        let receipt = chain.rb.tx(
+            &format!("tx bank send {key} {recipient} {amount}{denom}{flags}"),
+            true,
+        )?;
+
+        self.guard_tx_errors(
+            chain_name,
+            receipt
+                .get("txhash")
+                .and_then(|receipt| receipt.as_str())
+                .ok_or(Error::TxMissingLogs)?,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn tx_multi_send(
+        &mut self,
+        key: &str,
+        chain_name: &str,
+        recipients: &[&str],
+        denom: &str,
+        amount: u128,
+        split: bool,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let chain = self.get_chain(chain_name);
+        let flags = chain.tx_flags(tx_options, &MULTI_SEND_TX_OPTIONS)?;
+
+        let recipients = recipients.join(" ");
+        let split_part = if split { " --split" } else { "" };
+
+        let receipt = chain.rb.tx(
+            &format!("tx bank multi-send {key} {recipients} {amount}{denom}{split_part}{flags}"),
+            true,
+        )?;
+
+        self.guard_tx_errors(
+            chain_name,
+            receipt
+                .get("txhash")
+                .and_then(|receipt| receipt.as_str())
+                .ok_or(Error::TxMissingLogs)?,
+        )
+    }
+}
@@ -0,0 +1,249 @@
+use super::{
+    super::{
+        super::{error::Error, types::ibc::ChannelInfo, CCV_CONSUMER_PORT, CCV_PROVIDER_PORT},
+        test_context::{find_pairwise_ccv_channel_ids, TestContext},
+    },
+    ibc::query_json,
+};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The interval at which channels and packets are polled while waiting for CCV state.
+const CCV_POLL_INTERVAL_MILLIS: u64 = 1000;
+
+impl TestContext {
+    /// Waits for the CCV channel between a provider and a consumer chain to open,
+    /// re-reading channels from the relayer until it does. Records the channel under
+    /// the provider and consumer ports, and returns the provider and consumer ends.
+    pub fn wait_for_ccv_channel(
+        &mut self,
+        provider_chain_name: &str,
+        consumer_chain_name: &str,
+        timeout: Duration,
+    ) -> Result<(ChannelInfo, ChannelInfo), Error> {
+        let deadline = Instant::now() + timeout;
+
+        let (provider_channel, consumer_channel) = loop {
+            self.get_mut_chain(provider_chain_name).refresh_channels()?;
+            self.get_mut_chain(consumer_chain_name).refresh_channels()?;
+
+            let provider = self.get_chain(provider_chain_name);
+            let consumer = self.get_chain(consumer_chain_name);
+
+            if let Ok((provider_res, consumer_res)) =
+                find_pairwise_ccv_channel_ids(&provider.channels, &consumer.channels)
+            {
+                break (provider_res.channel, consumer_res.channel);
+            }
+
+            if Instant::now() > deadline {
+                return Err(Error::CcvChannelTimeout {
+                    provider: provider_chain_name.to_owned(),
+                    consumer: consumer_chain_name.to_owned(),
+                });
+            }
+
+            thread::sleep(Duration::from_millis(CCV_POLL_INTERVAL_MILLIS));
+        };
+
+        self.channels.insert(
+            (
+                provider_chain_name.to_owned(),
+                consumer_chain_name.to_owned(),
+                CCV_PROVIDER_PORT.to_owned(),
+            ),
+            provider_channel.clone(),
+        );
+        self.channels.insert(
+            (
+                consumer_chain_name.to_owned(),
+                provider_chain_name.to_owned(),
+                CCV_CONSUMER_PORT.to_owned(),
+            ),
+            consumer_channel.clone(),
+        );
+
+        Ok((provider_channel, consumer_channel))
+    }
+
+    /// Gets the sequences of validator set change packets sent by the provider chain
+    /// to a consumer chain that have not yet been acknowledged.
+    pub fn get_pending_vsc_packets(
+        &self,
+        provider_chain_name: &str,
+        consumer_chain_name: &str,
+    ) -> Result<Vec<u64>, Error> {
+        let channel = self
+            .get_channel()
+            .src(provider_chain_name)
+            .dest(consumer_chain_name)
+            .port(CCV_PROVIDER_PORT)
+            .try_get()?;
+        let cmd = format!(
+            "q ibc channel packet-commitments {CCV_PROVIDER_PORT} {}",
+            channel.channel_id
+        );
+
+        let res = query_json(&self.get_chain(provider_chain_name).rb, &cmd)?;
+
+        Ok(res["commitments"]
+            .as_array()
+            .map(|commitments| {
+                commitments
+                    .iter()
+                    .filter_map(|commitment| {
+                        let sequence = &commitment["sequence"];
+
+                        sequence
+                            .as_u64()
+                            .or_else(|| sequence.as_str().and_then(|s| s.parse().ok()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Relays all pending validator set change packets from the provider chain to
+    /// a consumer chain, and their acknowledgements back to the provider.
+    pub fn relay_vsc_packets(
+        &mut self,
+        provider_chain_name: &str,
+        consumer_chain_name: &str,
+    ) -> Result<(), Error> {
+        let channel = self
+            .get_channel()
+            .src(provider_chain_name)
+            .dest(consumer_chain_name)
+            .port(CCV_PROVIDER_PORT)
+            .try_get()?;
+        let path = self.relayer_path_name(provider_chain_name, consumer_chain_name)?;
+
+        self.relayer_exec(
+            provider_chain_name,
+            &format!("rly tx flush {path} {}", channel.channel_id),
+        )?;
+
+        Ok(())
+    }
+
+    /// Waits for the consumer chain to acknowledge every validator set change packet
+    /// sent to it by the provider chain.
+    pub fn wait_for_vsc_packets(
+        &self,
+        provider_chain_name: &str,
+        consumer_chain_name: &str,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let pending = self.get_pending_vsc_packets(provider_chain_name, consumer_chain_name)?;
+
+            if pending.is_empty() {
+                return Ok(());
+            }
+
+            if Instant::now() > deadline {
+                return Err(Error::MissingContextVariable(format!(
+                    "vsc_packets::{provider_chain_name}-{consumer_chain_name}::{pending:?}"
+                )));
+            }
+
+            thread::sleep(Duration::from_millis(CCV_POLL_INTERVAL_MILLIS));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::super::{
+            testing::{mock_context_builder, MockLocalIc},
+            NEUTRON_CHAIN_ID, NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_ID, OSMOSIS_CHAIN_NAME,
+        },
+        *,
+    };
+    use serde_json::{json, Value};
+
+    fn ccv_channel(port: &str, channel_id: &str, cp_port: &str, state: &str) -> Value {
+        json!({
+            "state": state,
+            "ordering": "ORDER_ORDERED",
+            "counterparty": { "port_id": cp_port, "channel_id": channel_id },
+            "connection_hops": ["connection-0"],
+            "version": "1",
+            "port_id": port,
+            "channel_id": channel_id,
+        })
+    }
+
+    #[test]
+    fn test_wait_for_ccv_channel_skips_channels_not_open() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_channels(
+            NEUTRON_CHAIN_ID,
+            json!([
+                ccv_channel(
+                    CCV_PROVIDER_PORT,
+                    "channel-0",
+                    CCV_CONSUMER_PORT,
+                    "STATE_TRYOPEN"
+                ),
+                ccv_channel(
+                    CCV_PROVIDER_PORT,
+                    "channel-1",
+                    CCV_CONSUMER_PORT,
+                    "STATE_OPEN"
+                ),
+            ]),
+        );
+        mock.on_channels(
+            OSMOSIS_CHAIN_ID,
+            json!([
+                ccv_channel(
+                    CCV_CONSUMER_PORT,
+                    "channel-0",
+                    CCV_PROVIDER_PORT,
+                    "STATE_INIT"
+                ),
+                ccv_channel(
+                    CCV_CONSUMER_PORT,
+                    "channel-1",
+                    CCV_PROVIDER_PORT,
+                    "STATE_OPEN"
+                ),
+            ]),
+        );
+
+        let mut ctx = mock_context_builder(&mock).build().unwrap();
+        let (provider, consumer) = ctx
+            .wait_for_ccv_channel(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, Duration::ZERO)
+            .unwrap();
+
+        assert_eq!(provider.channel_id, "channel-1");
+        assert_eq!(consumer.channel_id, "channel-1");
+    }
+
+    #[test]
+    fn test_wait_for_ccv_channel_times_out() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_channels(
+            NEUTRON_CHAIN_ID,
+            json!([ccv_channel(
+                CCV_PROVIDER_PORT,
+                "channel-0",
+                CCV_CONSUMER_PORT,
+                "STATE_TRYOPEN"
+            )]),
+        );
+
+        let mut ctx = mock_context_builder(&mock).build().unwrap();
+
+        assert!(matches!(
+            ctx.wait_for_ccv_channel(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, Duration::ZERO),
+            Err(Error::CcvChannelTimeout { .. })
+        ));
+    }
+}
@@ -1,18 +1,43 @@
 use super::super::{
     super::{
-        error::Error, DEFAULT_KEY, DEFAULT_TRANSFER_PORT, NEUTRON_CHAIN_ID, NEUTRON_CHAIN_NAME,
+        error::Error,
+        types::{
+            ibc::{get_ack_commitment, get_multihop_ibc_denom, Packet, ICS20_SUCCESS_ACK},
+            tx::{flag, TxOptions, TxReceipt},
+        },
+        DEFAULT_KEY, DEFAULT_TRANSFER_PORT, NEUTRON_CHAIN_NAME,
     },
     test_context::{LocalChain, TestContext},
 };
+use localic_std::transactions::ChainRequestBuilder;
+use serde_json::Value;
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The default options of transfers.
+const TRANSFER_TX_OPTIONS: TxOptions = TxOptions {
+    fee_amount: Some(100000),
+    ..TxOptions::EMPTY
+};
+
+/// The interval at which packet state is polled while waiting for acknowledgements.
+const PACKET_POLL_INTERVAL_MILLIS: u64 = 1000;
 
 pub struct TransferTxBuilder<'a> {
     key: &'a str,
     src_chain_name: &'a str,
+    dest_chain_name: Option<&'a str>,
     recipient: Option<&'a str>,
     denom: Option<&'a str>,
     amount: Option<u128>,
     memo: Option<&'a str>,
     port: &'a str,
+    timeout_height: Option<(u64, u64)>,
+    timeout_timestamp: Option<u64>,
+    absolute_timeouts: bool,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -29,6 +54,14 @@ impl<'a> TransferTxBuilder<'a> {
         self
     }
 
+    /// Sets the chain receiving the transfer. If not set, the destination chain
+    /// is the only chain whose bech32 prefix matches the recipient's address.
+    pub fn with_dest_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.dest_chain_name = Some(chain_name);
+
+        self
+    }
+
     pub fn with_recipient(&mut self, recipient: &'a str) -> &mut Self {
         self.recipient = Some(recipient);
 
@@ -59,11 +92,44 @@ impl<'a> TransferTxBuilder<'a> {
         self
     }
 
+    /// Sets the destination chain height at which the packet times out, as a
+    /// revision number and revision height. Relative to the destination chain's
+    /// latest height known by the source chain, unless absolute timeouts are used.
+    pub fn with_timeout_height(&mut self, revision_number: u64, revision_height: u64) -> &mut Self {
+        self.timeout_height = Some((revision_number, revision_height));
+
+        self
+    }
+
+    /// Sets the timestamp, in nanoseconds, at which the packet times out. Relative
+    /// to the destination chain's latest time known by the source chain, unless
+    /// absolute timeouts are used. A timestamp of 0 disables the timestamp timeout.
+    pub fn with_timeout_timestamp(&mut self, timeout_timestamp_nanos: u64) -> &mut Self {
+        self.timeout_timestamp = Some(timeout_timestamp_nanos);
+
+        self
+    }
+
+    /// Interprets the timeout height and timestamp as absolute values.
+    pub fn with_absolute_timeouts(&mut self, absolute_timeouts: bool) -> &mut Self {
+        self.absolute_timeouts = absolute_timeouts;
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the built IBC transfer tx.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_transfer(
             self.key,
             self.src_chain_name,
+            self.dest_chain_name,
             self.recipient
                 .ok_or(Error::MissingBuilderParam(String::from("recipient")))?,
             self.denom
@@ -72,6 +138,119 @@ impl<'a> TransferTxBuilder<'a> {
                 .ok_or(Error::MissingBuilderParam(String::from("amount")))?,
             self.port,
             self.memo,
+            self.timeout_height,
+            self.timeout_timestamp,
+            self.absolute_timeouts,
+            &self.tx_options,
+        )
+    }
+
+    /// Sends the built IBC transfer tx, and waits for the transferred packet to be
+    /// successfully acknowledged by the destination chain.
+    pub fn send_and_wait_for_ack(&mut self, timeout: Duration) -> Result<TxReceipt, Error> {
+        let mut receipt = self.send()?;
+
+        // Events are only available if logs are unwrapped
+        if receipt.events.is_empty() {
+            receipt = self
+                .test_ctx
+                .query_tx_receipt(self.src_chain_name, &receipt.hash)?;
+        }
+
+        let packet = Packet::from_receipt(&receipt)?;
+        self.test_ctx.wait_for_ack(
+            self.src_chain_name,
+            &packet,
+            Some(ICS20_SUCCESS_ACK),
+            timeout,
+        )?;
+
+        Ok(receipt)
+    }
+}
+
+/// A transfer forwarded over several chains by the packet-forward-middleware.
+pub struct MultihopTransferTxBuilder<'a> {
+    key: &'a str,
+    route: Vec<&'a str>,
+    recipient: Option<&'a str>,
+    denom: Option<&'a str>,
+    amount: Option<u128>,
+    forward_timeout: Option<&'a str>,
+    tx_options: TxOptions,
+    test_ctx: &'a mut TestContext,
+}
+
+/// The result of a multihop transfer.
+#[derive(Debug, Clone)]
+pub struct MultihopTransfer {
+    /// The receipt of the transfer on the first chain in the route
+    pub receipt: TxReceipt,
+    /// The denom of the transferred funds on the last chain in the route
+    pub final_denom: String,
+}
+
+impl<'a> MultihopTransferTxBuilder<'a> {
+    pub fn with_key(&mut self, key: &'a str) -> &mut Self {
+        self.key = key;
+
+        self
+    }
+
+    /// Sets the chains the funds are transferred over, from the sending chain to the receiving chain.
+    pub fn with_route(&mut self, route: &[&'a str]) -> &mut Self {
+        self.route = route.to_vec();
+
+        self
+    }
+
+    /// Sets the recipient of the funds on the last chain in the route.
+    pub fn with_recipient(&mut self, recipient: &'a str) -> &mut Self {
+        self.recipient = Some(recipient);
+
+        self
+    }
+
+    /// Sets the denom to transfer. Should be native to the first chain in the route.
+    pub fn with_denom(&mut self, denom: &'a str) -> &mut Self {
+        self.denom = Some(denom);
+
+        self
+    }
+
+    pub fn with_amount(&mut self, amount: u128) -> &mut Self {
+        self.amount = Some(amount);
+
+        self
+    }
+
+    /// Sets the timeout of each forwarded packet, e.g. "10m".
+    pub fn with_forward_timeout(&mut self, timeout: &'a str) -> &mut Self {
+        self.forward_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
+    /// Sends the built multihop transfer tx.
+    pub fn send(&mut self) -> Result<MultihopTransfer, Error> {
+        self.test_ctx.tx_multihop_transfer(
+            self.key,
+            &self.route,
+            self.recipient
+                .ok_or(Error::MissingBuilderParam(String::from("recipient")))?,
+            self.denom
+                .ok_or(Error::MissingBuilderParam(String::from("denom")))?,
+            self.amount
+                .ok_or(Error::MissingBuilderParam(String::from("amount")))?,
+            self.forward_timeout,
+            &self.tx_options,
         )
     }
 }
@@ -82,48 +261,180 @@ impl TestContext {
         TransferTxBuilder {
             key: DEFAULT_KEY,
             src_chain_name: NEUTRON_CHAIN_NAME,
+            dest_chain_name: Default::default(),
             recipient: Default::default(),
             denom: Default::default(),
             amount: Default::default(),
             memo: None,
             port: DEFAULT_TRANSFER_PORT,
+            timeout_height: Default::default(),
+            timeout_timestamp: Default::default(),
+            absolute_timeouts: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
 
+    /// Creates a builder building a transaction transfering funds over several
+    /// chains with the packet-forward-middleware.
+    pub fn build_tx_multihop_transfer(&mut self) -> MultihopTransferTxBuilder {
+        MultihopTransferTxBuilder {
+            key: DEFAULT_KEY,
+            route: Default::default(),
+            recipient: Default::default(),
+            denom: Default::default(),
+            amount: Default::default(),
+            forward_timeout: Default::default(),
+            tx_options: Default::default(),
+            test_ctx: self,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn tx_multihop_transfer(
+        &mut self,
+        key: &str,
+        route: &[&str],
+        recipient: &str,
+        denom: &str,
+        amount: u128,
+        forward_timeout: Option<&str>,
+        tx_options: &TxOptions,
+    ) -> Result<MultihopTransfer, Error> {
+        if route.len() < 2 {
+            return Err(Error::MissingBuilderParam(String::from("route")));
+        }
+
+        let transfer_channel =
+            |src: &str, dest: &str| self.get_transfer_channels().src(src).dest(dest).try_get();
+
+        // Build the memo from the last hop outwards. Funds are held by the signer's
+        // address on each intermediate chain before being forwarded.
+        let mut next = None;
+        let mut receiver = recipient.to_owned();
+
+        for hop in route.windows(2).skip(1).rev() {
+            let mut forward = serde_json::json!({
+                "receiver": receiver,
+                "port": DEFAULT_TRANSFER_PORT,
+                "channel": transfer_channel(hop[0], hop[1])?,
+            });
+
+            if let Some(timeout) = forward_timeout {
+                forward["timeout"] = Value::from(timeout);
+            }
+
+            if let Some(next) = next {
+                forward["next"] = next;
+            }
+
+            next = Some(serde_json::json!({ "forward": forward }));
+            receiver = self.address_of(hop[0], key)?;
+        }
+
+        let memo = next.map(|next| next.to_string());
+
+        // The denom is prefixed by the receiving channel of each hop,
+        // with the last hop outermost
+        let channel_trace = route
+            .windows(2)
+            .rev()
+            .map(|hop| transfer_channel(hop[1], hop[0]))
+            .collect::<Result<Vec<_>, _>>()?;
+        let final_denom =
+            get_multihop_ibc_denom(denom, channel_trace.iter().map(String::as_str).collect());
+
+        let receipt = self.tx_transfer(
+            key,
+            route[0],
+            Some(route[1]),
+            &receiver,
+            denom,
+            amount,
+            DEFAULT_TRANSFER_PORT,
+            memo.as_deref(),
+            None,
+            None,
+            false,
+            tx_options,
+        )?;
+
+        Ok(MultihopTransfer {
+            receipt,
+            final_denom,
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn tx_transfer(
         &mut self,
         key: &str,
         src_chain_name: &str,
+        dest_chain_name: Option<&str>,
         recipient: &str,
         denom: &str,
         amount: u128,
         port: &str,
         memo: Option<&str>,
-    ) -> Result<(), Error> {
-        let dest_chain: &LocalChain = self
-            .chains
-            .values()
-            .find(|chain| recipient.starts_with(&chain.chain_prefix))
-            .ok_or(Error::MissingContextVariable(format!("chain::{recipient}")))?;
+        timeout_height: Option<(u64, u64)>,
+        timeout_timestamp: Option<u64>,
+        absolute_timeouts: bool,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let dest_chain: &LocalChain = match dest_chain_name {
+            Some(dest_chain_name) => {
+                self.chains
+                    .get(dest_chain_name)
+                    .ok_or(Error::MissingContextVariable(format!(
+                        "chain::{dest_chain_name}"
+                    )))?
+            }
+            None => self.find_chain_by_address(recipient)?,
+        };
 
         let chain = self.get_chain(src_chain_name);
-        let fee_denom = &chain.native_denom;
+        let flags = chain.tx_flags(tx_options, &TRANSFER_TX_OPTIONS)?;
 
         let channel = self
-            .transfer_channel_ids
-            .get(&(chain.chain_name.clone(), dest_chain.chain_name.clone()))
+            .channels
+            .get(&(
+                chain.chain_name.clone(),
+                dest_chain.chain_name.clone(),
+                port.to_owned(),
+            ))
+            .map(|channel| &channel.channel_id)
             .ok_or(Error::MissingContextVariable(format!(
                 "channel_id::{}-{}",
                 chain.chain_name, dest_chain.chain_name
             )))?;
 
-        let memo_part = memo.map(|m| format!(" --memo={}", m)).unwrap_or_default();
+        // The memo of the builder takes precedence over the memo of the tx and chain options
+        let memo_part = TxOptions {
+            memo: memo.map(ToOwned::to_owned),
+            ..TxOptions::EMPTY
+        }
+        .or(tx_options)
+        .or(&chain.tx_options)
+        .memo
+        .map(|m| flag("memo", &m))
+        .transpose()?
+        .unwrap_or_default();
+        let timeout_height_part = timeout_height
+            .map(|(revision_number, revision_height)| {
+                format!(" --packet-timeout-height={revision_number}-{revision_height}")
+            })
+            .unwrap_or_default();
+        let timeout_timestamp_part = timeout_timestamp
+            .map(|timestamp| format!(" --packet-timeout-timestamp={timestamp}"))
+            .unwrap_or_default();
+        let absolute_timeouts_part = if absolute_timeouts {
+            " --absolute-timeouts"
+        } else {
+            ""
+        };
 
         let receipt = chain.rb.tx(&format!(
-            "tx ibc-transfer transfer {port} {channel} {recipient} {amount}{denom} --fees=100000{fee_denom} --from={key}{}",
-            memo_part
+            "tx ibc-transfer transfer {port} {channel} {recipient} {amount}{denom} --from={key}{memo_part}{timeout_height_part}{timeout_timestamp_part}{absolute_timeouts_part}{flags}",
         ), true)?;
 
         self.guard_tx_errors(
@@ -132,36 +443,292 @@ impl TestContext {
                 .get("txhash")
                 .and_then(|receipt| receipt.as_str())
                 .ok_or(Error::TxMissingLogs)?,
+        )
+    }
+
+    /// Finds the only chain whose bech32 prefix matches an address.
+    fn find_chain_by_address(&self, addr: &str) -> Result<&LocalChain, Error> {
+        // The separator is included, so that prefixes of other prefixes
+        // (e.g. neutron and neutronvaloper) do not match
+        let mut matching = self
+            .chains
+            .values()
+            .filter(|chain| addr.starts_with(&format!("{}1", chain.chain_prefix)));
+
+        let chain = matching
+            .next()
+            .ok_or(Error::MissingContextVariable(format!("chain::{addr}")))?;
+
+        if let Some(other) = matching.next() {
+            return Err(Error::Misc(format!(
+                "address {addr} matches chains {} and {}, specify the chain explicitly",
+                chain.chain_name, other.chain_name
+            )));
+        }
+
+        Ok(chain)
+    }
+
+    /// Finds the chain receiving a packet sent by the source chain.
+    fn find_counterparty_chain(
+        &self,
+        src_chain_name: &str,
+        packet: &Packet,
+    ) -> Result<&LocalChain, Error> {
+        let known_dest = self
+            .channels
+            .iter()
+            .find(|((src, _, port), channel)| {
+                src == src_chain_name
+                    && *port == packet.src_port
+                    && channel.channel_id == packet.src_channel
+            })
+            .map(|((_, dest, _), _)| dest);
+
+        if let Some(dest) = known_dest {
+            return Ok(self.get_chain(dest));
+        }
+
+        // Otherwise, the counterparty is the chain tracked by the channel's client
+        let client_state = query_json(
+            &self.get_chain(src_chain_name).rb,
+            &format!(
+                "q ibc channel client-state {} {}",
+                packet.src_port, packet.src_channel
+            ),
         )?;
+        let chain_id = client_state["identified_client_state"]["client_state"]["chain_id"]
+            .as_str()
+            .ok_or(Error::MissingContextVariable(format!(
+                "chain::{}",
+                packet.src_channel
+            )))?;
+
+        self.chains
+            .values()
+            .find(|chain| chain.rb.chain_id == chain_id)
+            .ok_or(Error::MissingContextVariable(format!("chain::{chain_id}")))
+    }
+
+    /// Waits for a packet sent by the source chain to be acknowledged by its destination
+    /// chain. If an acknowledgement is expected, errors if the packet was acknowledged
+    /// with another one. Also errors if the packet timed out, or was not acknowledged in time.
+    pub fn wait_for_ack(
+        &self,
+        src_chain_name: &str,
+        packet: &Packet,
+        expected_ack: Option<&str>,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let src_chain = self.get_chain(src_chain_name);
+        let dest_chain = self.find_counterparty_chain(src_chain_name, packet)?;
+
+        let deadline = Instant::now() + timeout;
+
+        // The commitment to a packet is removed from the source chain once it is
+        // either acknowledged or timed out
+        while query_optional_json(
+            &src_chain.rb,
+            &format!(
+                "q ibc channel packet-commitment {} {} {}",
+                packet.src_port, packet.src_channel, packet.sequence
+            ),
+        )?
+        .is_some_and(|res| res["commitment"].as_str().is_some_and(|c| !c.is_empty()))
+        {
+            if Instant::now() > deadline {
+                return Err(Error::AckTimeout {
+                    sequence: packet.sequence,
+                    channel: packet.src_channel.clone(),
+                });
+            }
+
+            thread::sleep(Duration::from_millis(PACKET_POLL_INTERVAL_MILLIS));
+        }
+
+        let received = query_optional_json(
+            &dest_chain.rb,
+            &format!(
+                "q ibc channel packet-receipt {} {} {}",
+                packet.dst_port, packet.dst_channel, packet.sequence
+            ),
+        )?
+        .and_then(|res| res["received"].as_bool())
+        .unwrap_or_default();
+
+        if !received {
+            return Err(Error::PacketTimedOut {
+                sequence: packet.sequence,
+                channel: packet.src_channel.clone(),
+            });
+        }
+
+        let ack = query_optional_json(
+            &dest_chain.rb,
+            &format!(
+                "q ibc channel packet-ack {} {} {}",
+                packet.dst_port, packet.dst_channel, packet.sequence
+            ),
+        )?
+        .and_then(|res| res["acknowledgement"].as_str().map(ToOwned::to_owned))
+        .unwrap_or_default();
+
+        if expected_ack.map_or(ack.is_empty(), |expected_ack| {
+            ack != get_ack_commitment(expected_ack)
+        }) {
+            return Err(Error::AckError {
+                sequence: packet.sequence,
+                channel: packet.src_channel.clone(),
+                ack,
+            });
+        }
 
         Ok(())
     }
 
-    pub fn start_relayer(&mut self) {
-        // Any random chain will allow us to kill the relayer,
-        // so select any chain we can get the API URL from
-        let chain = self.chains.values().next().unwrap();
+    /// Gets the acknowledgement of a packet sent by the source chain, as written
+    /// by its destination chain in the `write_acknowledgement` event of the tx
+    /// receiving the packet.
+    pub fn query_packet_ack(&self, src_chain_name: &str, packet: &Packet) -> Result<String, Error> {
+        let dest_chain = self.find_counterparty_chain(src_chain_name, packet)?;
+        let sequence = packet.sequence.to_string();
+
+        // Chains before SDK v0.50 only accept the deprecated --events flag
+        let txs = query_json(
+            &dest_chain.rb,
+            &format!("q txs --query=write_acknowledgement.packet_sequence='{sequence}'"),
+        )
+        .or_else(|_| {
+            query_json(
+                &dest_chain.rb,
+                &format!("q txs --events=write_acknowledgement.packet_sequence={sequence}"),
+            )
+        })?["txs"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+
+        txs.iter()
+            .map(|tx| TxReceipt::from_tx_response("", tx))
+            .find_map(|receipt| {
+                receipt
+                    .find_events("write_acknowledgement")
+                    .find(|event| {
+                        event.attr("packet_sequence") == Some(sequence.as_str())
+                            && event.attr("packet_dst_port") == Some(packet.dst_port.as_str())
+                            && event.attr("packet_dst_channel") == Some(packet.dst_channel.as_str())
+                    })
+                    .and_then(|event| event.attr("packet_ack"))
+                    .map(ToOwned::to_owned)
+            })
+            .ok_or_else(|| {
+                Error::MissingContextVariable(format!(
+                    "write_acknowledgement::{}-{sequence}",
+                    packet.dst_channel
+                ))
+            })
+    }
+}
 
-        // See above. chain_id does not matter, since there is
-        // one relayer running
-        reqwest::blocking::Client::default()
-            .post(&chain.rb.api)
-            .json(&serde_json::json!({ "chain_id": NEUTRON_CHAIN_ID, "action": "start-relayer"}))
-            .send()
+/// Runs a query with JSON output. Errors with the output of the query if it is not JSON.
+pub(crate) fn query_json(rb: &ChainRequestBuilder, cmd: &str) -> Result<Value, Error> {
+    let res = rb.query(&format!("{cmd} --output=json"), true);
+    let output = res["text"]
+        .as_str()
+        .ok_or_else(|| Error::ContainerCmd(cmd.to_owned()))?;
+
+    serde_json::from_str(output).map_err(|_| Error::QueryFailed {
+        cmd: cmd.to_owned(),
+        output: output.to_owned(),
+    })
+}
+
+/// Runs a query with JSON output, returning None if the chain reports that
+/// the queried value does not exist.
+pub(crate) fn query_optional_json(
+    rb: &ChainRequestBuilder,
+    cmd: &str,
+) -> Result<Option<Value>, Error> {
+    match query_json(rb, cmd) {
+        Ok(res) => Ok(Some(res)),
+        Err(Error::QueryFailed { output, .. })
+            if output.contains("NotFound") || output.contains("not found") =>
+        {
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::super::{
+            testing::{mock_context_builder, MockLocalIc},
+            OSMOSIS_CHAIN_NAME,
+        },
+        *,
+    };
+    use serde_json::json;
+
+    fn transfer_packet() -> Packet {
+        Packet {
+            sequence: 1,
+            src_port: String::from(DEFAULT_TRANSFER_PORT),
+            src_channel: String::from("channel-0"),
+            dst_port: String::from(DEFAULT_TRANSFER_PORT),
+            dst_channel: String::from("channel-1"),
+        }
+    }
+
+    #[test]
+    fn test_wait_for_ack_treats_missing_commitment_as_gone() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_query(
+            "packet-commitment",
+            "Error: rpc error: code = NotFound desc = packet commitment hash not found",
+        );
+        mock.on_query("packet-receipt", json!({ "received": true }).to_string());
+        mock.on_query(
+            "packet-ack",
+            json!({ "acknowledgement": get_ack_commitment(ICS20_SUCCESS_ACK) }).to_string(),
+        );
+
+        let ctx = mock_context_builder(&mock)
+            .with_transfer_channel_id(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, "channel-0")
+            .build()
             .unwrap();
+
+        ctx.wait_for_ack(
+            NEUTRON_CHAIN_NAME,
+            &transfer_packet(),
+            Some(ICS20_SUCCESS_ACK),
+            Duration::from_secs(1),
+        )
+        .unwrap();
     }
 
-    pub fn stop_relayer(&mut self) {
-        // Any random chain will allow us to kill the relayer,
-        // so select any chain we can get the API URL from
-        let chain = self.chains.values().next().unwrap();
+    #[test]
+    fn test_wait_for_ack_propagates_query_failures() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_query(
+            "packet-commitment",
+            "Error: post failed: connection refused",
+        );
 
-        // See above. chain_id does not matter, since there is
-        // one relayer running
-        reqwest::blocking::Client::default()
-            .post(&chain.rb.api)
-            .json(&serde_json::json!({ "chain_id": NEUTRON_CHAIN_ID, "action": "stop-relayer"}))
-            .send()
+        let ctx = mock_context_builder(&mock)
+            .with_transfer_channel_id(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, "channel-0")
+            .build()
             .unwrap();
+
+        assert!(matches!(
+            ctx.wait_for_ack(
+                NEUTRON_CHAIN_NAME,
+                &transfer_packet(),
+                None,
+                Duration::from_secs(1),
+            ),
+            Err(Error::QueryFailed { .. })
+        ));
     }
 }
@@ -0,0 +1,412 @@
+use super::{
+    super::{
+        super::{
+            error::Error,
+            types::{
+                ibc::Packet,
+                tx::{Gas, TxOptions, TxReceipt},
+            },
+            DEFAULT_KEY, GAIA_CHAIN_NAME, ICA_PACKET_FILE_DIR, NEUTRON_CHAIN_NAME,
+        },
+        test_context::TestContext,
+    },
+    ibc::query_optional_json,
+};
+use cosmwasm_std::Binary;
+use serde_json::Value;
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    process,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Numbers the packet files of interchain account txs, so that concurrent
+/// submissions do not overwrite each other's packets.
+static ICA_PACKET_NONCE: AtomicU64 = AtomicU64::new(0);
+
+/// The default time to wait for an interchain account's channel to open.
+const ICA_OPEN_TIMEOUT_SEC: u64 = 60;
+
+/// The interval at which interchain accounts are polled while waiting for them to open.
+const ICA_POLL_INTERVAL_MILLIS: u64 = 1000;
+
+/// The default options of interchain account txs, whose gas is estimated.
+const ICA_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Auto),
+    gas_adjustment: Some(1.3),
+    fee_amount: Some(100000),
+    ..TxOptions::EMPTY
+};
+
+/// An interchain account registered by an owner on a controller chain.
+#[derive(Debug, Clone)]
+pub struct InterchainAccount {
+    pub owner: String,
+    /// The connection on the controller chain
+    pub connection_id: String,
+    /// The address of the account on the host chain
+    pub address: String,
+    /// The receipt of the registration tx
+    pub receipt: TxReceipt,
+}
+
+/// A tx registering an interchain account, and waiting for its channel to open.
+pub struct RegisterIcaTxBuilder<'a> {
+    key: &'a str,
+    owner: Option<&'a str>,
+    controller_chain_name: &'a str,
+    host_chain_name: &'a str,
+    timeout: Duration,
+    tx_options: TxOptions,
+    test_ctx: &'a mut TestContext,
+}
+
+impl<'a> RegisterIcaTxBuilder<'a> {
+    pub fn with_key(&mut self, key: &'a str) -> &mut Self {
+        self.key = key;
+
+        self
+    }
+
+    /// Sets the address of the owner of the account. Should be the address of the signing key,
+    /// which it defaults to.
+    pub fn with_owner(&mut self, owner: &'a str) -> &mut Self {
+        self.owner = Some(owner);
+
+        self
+    }
+
+    pub fn with_controller_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.controller_chain_name = chain_name;
+
+        self
+    }
+
+    pub fn with_host_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.host_chain_name = chain_name;
+
+        self
+    }
+
+    /// Sets the time to wait for the account's channel to open.
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
+    /// Sends the transaction, returning the account once its channel has opened.
+    pub fn send(&mut self) -> Result<InterchainAccount, Error> {
+        self.test_ctx.tx_register_ica(
+            self.key,
+            self.owner,
+            self.controller_chain_name,
+            self.host_chain_name,
+            self.timeout,
+            &self.tx_options,
+        )
+    }
+}
+
+/// A tx executing messages on a host chain with an interchain account.
+pub struct SubmitIcaTxBuilder<'a> {
+    key: &'a str,
+    controller_chain_name: &'a str,
+    host_chain_name: &'a str,
+    msgs: Vec<Value>,
+    memo: &'a str,
+    tx_options: TxOptions,
+    test_ctx: &'a mut TestContext,
+}
+
+impl<'a> SubmitIcaTxBuilder<'a> {
+    pub fn with_key(&mut self, key: &'a str) -> &mut Self {
+        self.key = key;
+
+        self
+    }
+
+    pub fn with_controller_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.controller_chain_name = chain_name;
+
+        self
+    }
+
+    pub fn with_host_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.host_chain_name = chain_name;
+
+        self
+    }
+
+    /// Adds a message to execute on the host chain, in its proto3 JSON encoding,
+    /// e.g. `{ "@type": "/cosmos.bank.v1beta1.MsgSend", ... }`.
+    pub fn with_msg(&mut self, msg: Value) -> &mut Self {
+        self.msgs.push(msg);
+
+        self
+    }
+
+    pub fn with_memo(&mut self, memo: &'a str) -> &mut Self {
+        self.memo = memo;
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
+    /// Sends the transaction.
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
+        self.test_ctx.tx_submit_ica(
+            self.key,
+            self.controller_chain_name,
+            self.host_chain_name,
+            &self.msgs,
+            self.memo,
+            &self.tx_options,
+        )
+    }
+
+    /// Sends the transaction, and waits for the host chain to acknowledge the packet.
+    /// Errors if the host chain acknowledged the packet with an error, i.e. if any of
+    /// the messages failed.
+    pub fn send_and_wait_for_ack(&mut self, timeout: Duration) -> Result<TxReceipt, Error> {
+        let mut receipt = self.send()?;
+
+        // Events are only available if logs are unwrapped
+        if receipt.events.is_empty() {
+            receipt = self
+                .test_ctx
+                .query_tx_receipt(self.controller_chain_name, &receipt.hash)?;
+        }
+
+        let packet = Packet::from_receipt(&receipt)?;
+        self.test_ctx
+            .wait_for_ack(self.controller_chain_name, &packet, None, timeout)?;
+
+        // Interchain account acks wrap either the results of the messages, or an error
+        let ack = self
+            .test_ctx
+            .query_packet_ack(self.controller_chain_name, &packet)?;
+
+        if serde_json::from_str::<Value>(&ack)
+            .ok()
+            .and_then(|ack| ack.get("result").cloned())
+            .is_none()
+        {
+            return Err(Error::AckError {
+                sequence: packet.sequence,
+                channel: packet.src_channel,
+                ack,
+            });
+        }
+
+        Ok(receipt)
+    }
+}
+
+impl TestContext {
+    /// Creates a builder registering an interchain account.
+    pub fn build_tx_register_ica(&mut self) -> RegisterIcaTxBuilder {
+        RegisterIcaTxBuilder {
+            key: DEFAULT_KEY,
+            owner: Default::default(),
+            controller_chain_name: NEUTRON_CHAIN_NAME,
+            host_chain_name: GAIA_CHAIN_NAME,
+            timeout: Duration::from_secs(ICA_OPEN_TIMEOUT_SEC),
+            tx_options: Default::default(),
+            test_ctx: self,
+        }
+    }
+
+    /// Creates a builder executing messages with an interchain account owned by the signer.
+    pub fn build_tx_submit_ica(&mut self) -> SubmitIcaTxBuilder {
+        SubmitIcaTxBuilder {
+            key: DEFAULT_KEY,
+            controller_chain_name: NEUTRON_CHAIN_NAME,
+            host_chain_name: GAIA_CHAIN_NAME,
+            msgs: Default::default(),
+            memo: "",
+            tx_options: Default::default(),
+            test_ctx: self,
+        }
+    }
+
+    /// Gets the address of the interchain account of an owner on a host chain,
+    /// if the account's channel has opened.
+    pub fn get_ica_address(
+        &self,
+        owner: &str,
+        controller_chain_name: &str,
+        host_chain_name: &str,
+    ) -> Result<Option<String>, Error> {
+        let connection_id = self
+            .get_connections()
+            .src(controller_chain_name)
+            .dest(host_chain_name)
+            .try_get()?;
+        let controller = self.get_chain(controller_chain_name);
+
+        Ok(query_optional_json(
+            &controller.rb,
+            &format!("q interchain-accounts controller interchain-account {owner} {connection_id}"),
+        )?
+        .and_then(|res| res["address"].as_str().map(ToOwned::to_owned))
+        .filter(|addr| !addr.is_empty()))
+    }
+
+    fn tx_register_ica(
+        &mut self,
+        key: &str,
+        owner: Option<&str>,
+        controller_chain_name: &str,
+        host_chain_name: &str,
+        timeout: Duration,
+        tx_options: &TxOptions,
+    ) -> Result<InterchainAccount, Error> {
+        let connection_id = self
+            .get_connections()
+            .src(controller_chain_name)
+            .dest(host_chain_name)
+            .try_get()?;
+        let host_connection_id = self
+            .get_connections()
+            .src(host_chain_name)
+            .dest(controller_chain_name)
+            .try_get()?;
+
+        let owner = match owner {
+            Some(owner) => owner.to_owned(),
+            None => self.address_of(controller_chain_name, key)?,
+        };
+
+        let controller = self.get_chain(controller_chain_name);
+
+        // JSON encoding allows txs to be built without protobuf definitions
+        let version = serde_json::json!({
+            "version": "ics27-1",
+            "controller_connection_id": connection_id,
+            "host_connection_id": host_connection_id,
+            "address": "",
+            "encoding": "proto3json",
+            "tx_type": "sdk_multi_msg",
+        });
+
+        let receipt = controller.rb.tx(
+            &format!("tx interchain-accounts controller register {connection_id} --version {version} --from {key}{}", controller.tx_flags(tx_options, &ICA_TX_OPTIONS)?),
+            true,
+        )?;
+
+        let receipt = self.guard_tx_errors(
+            controller_chain_name,
+            receipt
+                .get("txhash")
+                .and_then(|receipt| receipt.as_str())
+                .ok_or(Error::TxMissingLogs)?,
+        )?;
+
+        let deadline = Instant::now() + timeout;
+
+        let address = loop {
+            if let Some(address) =
+                self.get_ica_address(&owner, controller_chain_name, host_chain_name)?
+            {
+                break address;
+            }
+
+            if Instant::now() > deadline {
+                return Err(Error::MissingContextVariable(format!(
+                    "interchain_account::{owner}-{connection_id}"
+                )));
+            }
+
+            thread::sleep(Duration::from_millis(ICA_POLL_INTERVAL_MILLIS));
+        };
+
+        Ok(InterchainAccount {
+            owner,
+            connection_id,
+            address,
+            receipt,
+        })
+    }
+
+    fn tx_submit_ica(
+        &mut self,
+        key: &str,
+        controller_chain_name: &str,
+        host_chain_name: &str,
+        msgs: &[Value],
+        memo: &str,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let connection_id = self
+            .get_connections()
+            .src(controller_chain_name)
+            .dest(host_chain_name)
+            .try_get()?;
+
+        let controller = self.get_chain(controller_chain_name);
+
+        // Build the packet locally, since the controller CLI reads it from a file
+        let cosmos_tx = serde_json::json!({ "messages": msgs }).to_string();
+        let packet_data = serde_json::json!({
+            "type": "TYPE_EXECUTE_TX",
+            "data": Binary::from(cosmos_tx.as_bytes()).to_base64(),
+            "memo": memo,
+        });
+
+        let chain_id = &controller.rb.chain_id;
+        let packet_file_name = format!(
+            "ica_packet_{chain_id}_{}_{}.json",
+            process::id(),
+            ICA_PACKET_NONCE.fetch_add(1, Ordering::Relaxed)
+        );
+        let packet_path = Path::new(ICA_PACKET_FILE_DIR).join(&packet_file_name);
+
+        let mut f = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&packet_path)?;
+        f.write_all(packet_data.to_string().as_bytes())?;
+
+        controller
+            .rb
+            .upload_file(&packet_path, true)?
+            .send()?
+            .text()?;
+        fs::remove_file(&packet_path)?;
+
+        // Uploaded files are placed in the chain's home directory under the same name
+        let remote_packet_path = format!("/var/cosmos-chain/{chain_id}/{packet_file_name}");
+
+        let receipt = controller.rb.tx(
+            &format!("tx interchain-accounts controller send-tx {connection_id} {remote_packet_path} --from {key}{}", controller.tx_flags(tx_options, &ICA_TX_OPTIONS)?),
+            true,
+        )?;
+
+        self.guard_tx_errors(
+            controller_chain_name,
+            receipt
+                .get("txhash")
+                .and_then(|receipt| receipt.as_str())
+                .ok_or(Error::TxMissingLogs)?,
+        )
+    }
+}
@@ -0,0 +1,438 @@
+use super::{
+    super::{
+        super::{
+            error::Error,
+            types::tx::{Gas, TxOptions, TxReceipt},
+            DEFAULT_KEY, GAIA_CHAIN_NAME, NEUTRON_CHAIN_NAME,
+        },
+        test_context::TestContext,
+    },
+    ibc::query_json,
+};
+use serde_json::Value;
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The default options of query registrations, whose gas is estimated.
+const ICQ_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Auto),
+    gas_adjustment: Some(1.3),
+    fee_amount: Some(100000),
+    ..TxOptions::EMPTY
+};
+
+/// The default number of remote blocks between updates of a registered query.
+const ICQ_UPDATE_PERIOD: u64 = 5;
+
+/// The interval at which registered queries are polled while waiting for results.
+const ICQ_POLL_INTERVAL_MILLIS: u64 = 1000;
+
+/// A key in the store of a module on the remote chain, queried by a KV interchain query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KvKey {
+    /// The name of the store, e.g. "bank"
+    pub path: String,
+    /// The base64 encoded key in the store
+    pub key: String,
+}
+
+/// An interchain query registered by a contract.
+#[derive(Debug, Clone)]
+pub struct RegisteredIcq {
+    pub query_id: u64,
+    /// The connection on the chain the query was registered on
+    pub connection_id: String,
+    /// The receipt of the registration tx
+    pub receipt: TxReceipt,
+}
+
+/// A tx registering a KV or TX interchain query through a contract on Neutron.
+///
+/// Neutron only allows contracts to register interchain queries, so the
+/// registering contract is expected to forward the message
+/// `{ "register_interchain_query": { query_type, keys, transactions_filter, connection_id, update_period } }`
+/// as a `NeutronMsg::RegisterInterchainQuery`, unless a custom message is provided with `with_msg`.
+pub struct RegisterIcqTxBuilder<'a> {
+    key: &'a str,
+    chain_name: &'a str,
+    host_chain_name: &'a str,
+    contract: Option<&'a str>,
+    kv_keys: Vec<KvKey>,
+    tx_filter: Option<Value>,
+    update_period: u64,
+    deposit: Option<&'a str>,
+    msg: Option<Value>,
+    tx_options: TxOptions,
+    test_ctx: &'a mut TestContext,
+}
+
+impl<'a> RegisterIcqTxBuilder<'a> {
+    pub fn with_key(&mut self, key: &'a str) -> &mut Self {
+        self.key = key;
+
+        self
+    }
+
+    pub fn with_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.chain_name = chain_name;
+
+        self
+    }
+
+    /// Sets the chain queried. The query is registered on the connection to this chain.
+    pub fn with_host_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.host_chain_name = chain_name;
+
+        self
+    }
+
+    /// Sets the address of the contract registering the query.
+    pub fn with_contract(&mut self, contract: &'a str) -> &mut Self {
+        self.contract = Some(contract);
+
+        self
+    }
+
+    /// Adds a key to a KV query.
+    pub fn with_kv_key(&mut self, path: &str, key: &str) -> &mut Self {
+        self.kv_keys.push(KvKey {
+            path: path.to_owned(),
+            key: key.to_owned(),
+        });
+
+        self
+    }
+
+    /// Registers a TX query, with a filter of the form `[{ "field": ..., "op": ..., "value": ... }]`.
+    pub fn with_tx_filter(&mut self, filter: Value) -> &mut Self {
+        self.tx_filter = Some(filter);
+
+        self
+    }
+
+    /// Sets the number of remote blocks between updates of the query.
+    pub fn with_update_period(&mut self, update_period: u64) -> &mut Self {
+        self.update_period = update_period;
+
+        self
+    }
+
+    /// Sets the funds sent to the contract to cover the query deposit, e.g. "1000000untrn".
+    /// Defaults to the deposit required by the interchainqueries module.
+    pub fn with_deposit(&mut self, deposit: &'a str) -> &mut Self {
+        self.deposit = Some(deposit);
+
+        self
+    }
+
+    /// Sets the message executed on the contract to register the query,
+    /// for contracts with their own registration messages.
+    pub fn with_msg(&mut self, msg: Value) -> &mut Self {
+        self.msg = Some(msg);
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
+    /// Sends the transaction, returning the ID of the registered query.
+    pub fn send(&mut self) -> Result<RegisteredIcq, Error> {
+        let contract = self
+            .contract
+            .ok_or(Error::MissingBuilderParam(String::from("contract")))?;
+
+        self.test_ctx.tx_register_icq(
+            self.key,
+            self.chain_name,
+            self.host_chain_name,
+            contract,
+            &self.kv_keys,
+            self.tx_filter.as_ref(),
+            self.update_period,
+            self.deposit,
+            self.msg.as_ref(),
+            &self.tx_options,
+        )
+    }
+}
+
+impl TestContext {
+    /// Creates a builder registering an interchain query on Neutron.
+    pub fn build_tx_register_icq(&mut self) -> RegisterIcqTxBuilder {
+        RegisterIcqTxBuilder {
+            key: DEFAULT_KEY,
+            chain_name: NEUTRON_CHAIN_NAME,
+            host_chain_name: GAIA_CHAIN_NAME,
+            contract: Default::default(),
+            kv_keys: Default::default(),
+            tx_filter: Default::default(),
+            update_period: ICQ_UPDATE_PERIOD,
+            deposit: Default::default(),
+            msg: Default::default(),
+            tx_options: Default::default(),
+            test_ctx: self,
+        }
+    }
+
+    /// Lists the interchain queries registered on a chain, optionally only those
+    /// owned by the given address.
+    pub fn get_registered_icqs(
+        &self,
+        chain_name: &str,
+        owner: Option<&str>,
+    ) -> Result<Vec<Value>, Error> {
+        let owner_part = owner
+            .map(|owner| format!(" --owners {owner}"))
+            .unwrap_or_default();
+        let cmd = format!("q interchainqueries registered-queries{owner_part}");
+
+        let res = query_json(&self.get_chain(chain_name).rb, &cmd)?;
+
+        Ok(res["registered_queries"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Gets an interchain query registered on a chain.
+    pub fn get_registered_icq(&self, chain_name: &str, query_id: u64) -> Result<Value, Error> {
+        let cmd = format!("q interchainqueries registered-query {query_id}");
+
+        let query = query_json(&self.get_chain(chain_name).rb, &cmd)?["registered_query"].take();
+
+        if query.is_null() {
+            return Err(Error::ContainerCmd(cmd));
+        }
+
+        Ok(query)
+    }
+
+    /// Gets the last result submitted for a KV interchain query.
+    pub fn get_icq_result(&self, chain_name: &str, query_id: u64) -> Result<Value, Error> {
+        let cmd = format!("q interchainqueries query-result {query_id}");
+
+        let result = query_json(&self.get_chain(chain_name).rb, &cmd)?["result"].take();
+
+        if result.is_null() {
+            return Err(Error::ContainerCmd(cmd));
+        }
+
+        Ok(result)
+    }
+
+    /// Waits for a result from at least the given remote height to be submitted
+    /// for an interchain query, returning the registered query.
+    /// Results are submitted by the ICQ relayer, which must be running.
+    pub fn wait_for_icq_update(
+        &self,
+        chain_name: &str,
+        query_id: u64,
+        remote_height: u64,
+        timeout: Duration,
+    ) -> Result<Value, Error> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let query = self.get_registered_icq(chain_name, query_id)?;
+            let submitted_height = &query["last_submitted_result_remote_height"]["revision_height"];
+            let submitted_height = submitted_height
+                .as_u64()
+                .or_else(|| submitted_height.as_str().and_then(|h| h.parse().ok()))
+                .unwrap_or_default();
+
+            if submitted_height >= remote_height {
+                return Ok(query);
+            }
+
+            if Instant::now() > deadline {
+                return Err(Error::MissingContextVariable(format!(
+                    "icq_result::{query_id}@{remote_height}"
+                )));
+            }
+
+            thread::sleep(Duration::from_millis(ICQ_POLL_INTERVAL_MILLIS));
+        }
+    }
+
+    /// Waits for a result from at least the given remote height to be submitted
+    /// for a KV interchain query, and gets the result.
+    pub fn wait_for_icq_result(
+        &self,
+        chain_name: &str,
+        query_id: u64,
+        remote_height: u64,
+        timeout: Duration,
+    ) -> Result<Value, Error> {
+        self.wait_for_icq_update(chain_name, query_id, remote_height, timeout)?;
+
+        self.get_icq_result(chain_name, query_id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn tx_register_icq(
+        &mut self,
+        key: &str,
+        chain_name: &str,
+        host_chain_name: &str,
+        contract: &str,
+        kv_keys: &[KvKey],
+        tx_filter: Option<&Value>,
+        update_period: u64,
+        deposit: Option<&str>,
+        msg: Option<&Value>,
+        tx_options: &TxOptions,
+    ) -> Result<RegisteredIcq, Error> {
+        let connection_id = self
+            .get_connections()
+            .src(chain_name)
+            .dest(host_chain_name)
+            .try_get()?;
+
+        let msg = match (msg, tx_filter) {
+            (Some(msg), _) => msg.clone(),
+            (None, Some(filter)) => serde_json::json!({
+                "register_interchain_query": {
+                    "query_type": "tx",
+                    "keys": [],
+                    "transactions_filter": filter.to_string(),
+                    "connection_id": connection_id,
+                    "update_period": update_period,
+                }
+            }),
+            (None, None) if !kv_keys.is_empty() => serde_json::json!({
+                "register_interchain_query": {
+                    "query_type": "kv",
+                    "keys": kv_keys
+                        .iter()
+                        .map(|KvKey { path, key }| serde_json::json!({ "path": path, "key": key }))
+                        .collect::<Vec<_>>(),
+                    "transactions_filter": "",
+                    "connection_id": connection_id,
+                    "update_period": update_period,
+                }
+            }),
+            (None, None) => {
+                return Err(Error::MissingBuilderParam(String::from(
+                    "kv_keys or tx_filter",
+                )))
+            }
+        };
+
+        let chain = self.get_chain(chain_name);
+
+        // The contract pays the deposit for the query out of the funds it is sent
+        let deposit = match deposit {
+            Some(deposit) => deposit.to_owned(),
+            None => query_json(&chain.rb, "q interchainqueries params")?["params"]["query_deposit"]
+                .as_array()
+                .map(|coins| {
+                    coins
+                        .iter()
+                        .filter_map(|coin| {
+                            Some(format!(
+                                "{}{}",
+                                coin["amount"].as_str()?,
+                                coin["denom"].as_str()?
+                            ))
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .unwrap_or_default(),
+        };
+        let amt_part = if deposit.is_empty() {
+            String::new()
+        } else {
+            format!("--amount {deposit} ")
+        };
+        let flags = chain.tx_flags(tx_options, &ICQ_TX_OPTIONS)?;
+
+        let receipt = chain.rb.tx(
+            &format!("tx wasm execute {contract} {msg} {amt_part}--from {key}{flags}"),
+            true,
+        )?;
+
+        let mut receipt = self.guard_tx_errors(
+            chain_name,
+            receipt
+                .get("txhash")
+                .and_then(|receipt| receipt.as_str())
+                .ok_or(Error::TxMissingLogs)?,
+        )?;
+
+        // Events are only available if logs are unwrapped
+        if receipt.events.is_empty() {
+            receipt = self.query_tx_receipt(chain_name, &receipt.hash)?;
+        }
+
+        let query_id = receipt
+            .assert_event_emitted("neutron", &[("action", "query_registered")])?
+            .attr("query_id")
+            .and_then(|id| id.parse().ok())
+            .ok_or(Error::MissingContextVariable(String::from("query_id")))?;
+
+        Ok(RegisteredIcq {
+            query_id,
+            connection_id,
+            receipt,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::super::{
+            testing::{mock_context_builder, MockLocalIc},
+            OSMOSIS_CHAIN_NAME,
+        },
+        *,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_register_icq_without_unwrapped_logs() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_tx("tx wasm execute", json!({ "txhash": "ABC" }).to_string());
+        mock.on_query(
+            "tx ABC",
+            json!({
+                "txhash": "ABC",
+                "code": 0,
+                "raw_log": "",
+                "events": [{
+                    "type": "neutron",
+                    "attributes": [
+                        { "key": "action", "value": "query_registered" },
+                        { "key": "query_id", "value": "7" },
+                    ],
+                }],
+            })
+            .to_string(),
+        );
+
+        let mut ctx = mock_context_builder(&mock)
+            .with_connection_id(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, "connection-0")
+            .build()
+            .unwrap();
+        let icq = ctx
+            .build_tx_register_icq()
+            .with_host_chain(OSMOSIS_CHAIN_NAME)
+            .with_contract("neutron1contract")
+            .with_kv_key("bank", "AA==")
+            .with_deposit("1000000untrn")
+            .send()
+            .unwrap();
+
+        assert_eq!(icq.query_id, 7);
+        assert_eq!(icq.connection_id, "connection-0");
+        assert_eq!(icq.receipt.hash, "ABC");
+    }
+}
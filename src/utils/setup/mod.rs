@@ -1,6 +1,12 @@
+pub mod accounts;
 pub mod astroport;
+pub mod bank;
+pub mod ccv;
 pub mod ibc;
+pub mod ica;
+pub mod icq;
 pub mod osmosis;
+pub mod relayer;
 pub mod stride;
 pub mod tokens;
 pub mod valence;
@@ -1,10 +1,21 @@
 use super::super::{
-    super::{error::Error, DEFAULT_KEY, OSMOSIS_CHAIN_NAME, OSMOSIS_POOLFILE_PATH},
+    super::{
+        error::Error,
+        types::tx::{Gas, TxOptions, TxReceipt},
+        DEFAULT_KEY, OSMOSIS_CHAIN_NAME, OSMOSIS_POOLFILE_PATH,
+    },
     test_context::TestContext,
 };
 use cosmwasm_std::Decimal;
 use std::{fs::OpenOptions, io::Write, path::Path};
 
+/// The default options of osmosis pool txs.
+const OSMOSIS_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Limit(1000000)),
+    fee_amount: Some(2500),
+    ..TxOptions::EMPTY
+};
+
 pub struct CreateOsmoPoolTxBuilder<'a> {
     key: &'a str,
     weights: Vec<(u64, &'a str)>,
@@ -12,6 +23,7 @@ pub struct CreateOsmoPoolTxBuilder<'a> {
     swap_fee: Decimal,
     exit_fee: Decimal,
     future_governor: &'a str,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -52,8 +64,15 @@ impl<'a> CreateOsmoPoolTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction, returning the pool ID if it was created successfully.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_create_osmo_pool(
             self.key,
             self.weights.iter().cloned(),
@@ -61,6 +80,7 @@ impl<'a> CreateOsmoPoolTxBuilder<'a> {
             self.swap_fee,
             self.exit_fee,
             self.future_governor,
+            &self.tx_options,
         )
     }
 }
@@ -70,6 +90,7 @@ pub struct FundOsmoPoolTxBuilder<'a> {
     pool_id: Option<u64>,
     max_amounts_in: Vec<(u64, &'a str)>,
     share_amount_out: Option<u64>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -98,8 +119,15 @@ impl<'a> FundOsmoPoolTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction, returning the pool ID if it was created successfully.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_fund_osmo_pool(
             self.key,
             self.pool_id
@@ -107,6 +135,7 @@ impl<'a> FundOsmoPoolTxBuilder<'a> {
             self.max_amounts_in.iter().cloned(),
             self.share_amount_out
                 .ok_or(Error::MissingBuilderParam(String::from("share_amount_out")))?,
+            &self.tx_options,
         )
     }
 }
@@ -120,11 +149,13 @@ impl TestContext {
             swap_fee: Decimal::percent(0),
             exit_fee: Decimal::percent(0),
             future_governor: "128h",
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
 
     /// Creates an osmosis pool with the given denoms.
+    #[allow(clippy::too_many_arguments)]
     fn tx_create_osmo_pool<'a>(
         &mut self,
         key: &str,
@@ -133,7 +164,8 @@ impl TestContext {
         swap_fee: Decimal,
         exit_fee: Decimal,
         future_governor: &'a str,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         let osmosis = self.get_chain(OSMOSIS_CHAIN_NAME);
 
         // Osmosisd requires a JSON file to specify the
@@ -166,7 +198,10 @@ impl TestContext {
 
         // Create pool
         let receipt = osmosis.rb.tx(
-            format!("tx poolmanager create-pool --pool-file {remote_poolfile_path} --from {key} --fees 2500uosmo --gas 1000000")
+            format!(
+                "tx poolmanager create-pool --pool-file {remote_poolfile_path} --from {key}{}",
+                osmosis.tx_flags(tx_options, &OSMOSIS_TX_OPTIONS)?
+            )
             .as_str(),
             true,
         )?;
@@ -177,9 +212,7 @@ impl TestContext {
                 .get("txhash")
                 .and_then(|receipt| receipt.as_str())
                 .ok_or(Error::TxMissingLogs)?,
-        )?;
-
-        Ok(())
+        )
     }
 
     pub fn build_tx_fund_osmo_pool(&mut self) -> FundOsmoPoolTxBuilder {
@@ -188,6 +221,7 @@ impl TestContext {
             pool_id: Default::default(),
             max_amounts_in: Default::default(),
             share_amount_out: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -199,12 +233,13 @@ impl TestContext {
         pool_id: u64,
         max_amounts_in: impl Iterator<Item = (u64, &'a str)>,
         share_amount_out: u64,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         let osmosis = self.get_chain(OSMOSIS_CHAIN_NAME);
 
         // Enter LP
         let receipt = osmosis.rb.tx(
-            format!("tx gamm join-pool --pool-id {pool_id} --max-amounts-in {} --share-amount-out {share_amount_out} --from {key} --fees 2500uosmo --gas 1000000", max_amounts_in.map(|(weight, denom)| format!("{weight}{denom}")).collect::<Vec<_>>().join(","))
+            format!("tx gamm join-pool --pool-id {pool_id} --max-amounts-in {} --share-amount-out {share_amount_out} --from {key}{}", max_amounts_in.map(|(weight, denom)| format!("{weight}{denom}")).collect::<Vec<_>>().join(","), osmosis.tx_flags(tx_options, &OSMOSIS_TX_OPTIONS)?)
             .as_str(),
             true,
         )?;
@@ -215,8 +250,6 @@ impl TestContext {
                 .get("txhash")
                 .and_then(|receipt| receipt.as_str())
                 .ok_or(Error::TxMissingLogs)?,
-        )?;
-
-        Ok(())
+        )
     }
 }
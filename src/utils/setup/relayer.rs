@@ -0,0 +1,385 @@
+use super::super::{
+    super::{
+        error::Error,
+        types::ibc::{Channel as QueryChannel, ChannelInfo, ChannelOrder},
+        DEFAULT_TRANSFER_PORT, NEUTRON_CHAIN_NAME, TRANSFER_PORT,
+    },
+    test_context::TestContext,
+};
+use localic_std::relayer::Relayer;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// The channel version used by ICS-20 transfer channels.
+const ICS20_VERSION: &str = "ics20-1";
+
+/// A channel opened between two chains by the relayer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedChannel {
+    pub src_channel_id: String,
+    pub dest_channel_id: String,
+    pub src_connection_id: String,
+    pub dest_connection_id: String,
+}
+
+/// A builder opening a new IBC channel between two chains with the relayer.
+pub struct CreateChannelTxBuilder<'a> {
+    src_chain_name: &'a str,
+    dest_chain_name: Option<&'a str>,
+    src_port: &'a str,
+    dest_port: &'a str,
+    version: &'a str,
+    order: ChannelOrder,
+    new_connection: bool,
+    test_ctx: &'a mut TestContext,
+}
+
+impl<'a> CreateChannelTxBuilder<'a> {
+    pub fn with_src_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.src_chain_name = chain_name;
+
+        self
+    }
+
+    pub fn with_dest_chain(&mut self, chain_name: &'a str) -> &mut Self {
+        self.dest_chain_name = Some(chain_name);
+
+        self
+    }
+
+    pub fn with_src_port(&mut self, port: &'a str) -> &mut Self {
+        self.src_port = port;
+
+        self
+    }
+
+    pub fn with_dest_port(&mut self, port: &'a str) -> &mut Self {
+        self.dest_port = port;
+
+        self
+    }
+
+    pub fn with_version(&mut self, version: &'a str) -> &mut Self {
+        self.version = version;
+
+        self
+    }
+
+    /// Sets the ordering of the channel. Defaults to unordered.
+    pub fn with_order(&mut self, order: ChannelOrder) -> &mut Self {
+        self.order = order;
+
+        self
+    }
+
+    /// Creates new clients and a new connection for the channel, instead of
+    /// reusing the ones already on the relayer path.
+    pub fn with_new_connection(&mut self, new_connection: bool) -> &mut Self {
+        self.new_connection = new_connection;
+
+        self
+    }
+
+    /// Opens the channel.
+    pub fn send(&mut self) -> Result<CreatedChannel, Error> {
+        self.test_ctx.tx_create_channel(
+            self.src_chain_name,
+            self.dest_chain_name
+                .ok_or(Error::MissingBuilderParam(String::from("dest_chain")))?,
+            self.src_port,
+            self.dest_port,
+            self.version,
+            self.order,
+            self.new_connection,
+        )
+    }
+}
+
+/// Sequences of packets or acknowledgements that have not yet been relayed.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct PendingPackets {
+    /// Sequences sent by the source chain
+    #[serde(default)]
+    pub src: Vec<u64>,
+    /// Sequences sent by the destination chain
+    #[serde(default)]
+    pub dst: Vec<u64>,
+}
+
+impl PendingPackets {
+    pub fn is_empty(&self) -> bool {
+        self.src.is_empty() && self.dst.is_empty()
+    }
+}
+
+impl TestContext {
+    /// Creates a builder opening a new IBC channel between two chains.
+    pub fn build_tx_create_channel(&mut self) -> CreateChannelTxBuilder {
+        CreateChannelTxBuilder {
+            src_chain_name: NEUTRON_CHAIN_NAME,
+            dest_chain_name: Default::default(),
+            src_port: DEFAULT_TRANSFER_PORT,
+            dest_port: DEFAULT_TRANSFER_PORT,
+            version: ICS20_VERSION,
+            order: ChannelOrder::default(),
+            new_connection: false,
+            test_ctx: self,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn tx_create_channel(
+        &mut self,
+        src_chain_name: &str,
+        dest_chain_name: &str,
+        src_port: &str,
+        dest_port: &str,
+        version: &str,
+        order: ChannelOrder,
+        new_connection: bool,
+    ) -> Result<CreatedChannel, Error> {
+        let path = self.relayer_path_name(src_chain_name, dest_chain_name)?;
+        let existing = self.relayer_channels(src_chain_name, dest_chain_name)?;
+
+        let override_part = if new_connection { " --override" } else { "" };
+        self.relayer_exec(
+            src_chain_name,
+            &format!(
+                "rly tx link {path} --src-port {src_port} --dst-port {dest_port} --order {} --version {version}{override_part}",
+                order.as_flag()
+            ),
+        )?;
+
+        // The new channel is the open channel on the ports that did not exist before linking
+        let src_channel = self
+            .relayer_channels(src_chain_name, dest_chain_name)?
+            .into_iter()
+            .rev()
+            .find(|channel| {
+                channel.port_id == src_port
+                    && channel.counterparty.port_id == dest_port
+                    && channel.state == "STATE_OPEN"
+                    && !existing.iter().any(|c| c.channel_id == channel.channel_id)
+            })
+            .ok_or(Error::MissingContextVariable(format!(
+                "channel_id::{src_chain_name}-{dest_chain_name}"
+            )))?;
+        let dest_channel = self
+            .relayer_channels(dest_chain_name, src_chain_name)?
+            .into_iter()
+            .find(|channel| {
+                channel.port_id == dest_port
+                    && channel.channel_id == src_channel.counterparty.channel_id
+            })
+            .ok_or(Error::MissingContextVariable(format!(
+                "channel_id::{dest_chain_name}-{src_chain_name}"
+            )))?;
+
+        let created = CreatedChannel {
+            src_channel_id: src_channel.channel_id.clone(),
+            dest_channel_id: dest_channel.channel_id.clone(),
+            src_connection_id: src_channel
+                .connection_hops
+                .first()
+                .cloned()
+                .unwrap_or_default(),
+            dest_connection_id: dest_channel
+                .connection_hops
+                .first()
+                .cloned()
+                .unwrap_or_default(),
+        };
+
+        self.channels.insert(
+            (
+                src_chain_name.to_owned(),
+                dest_chain_name.to_owned(),
+                src_port.to_owned(),
+            ),
+            ChannelInfo::from(&src_channel),
+        );
+        self.channels.insert(
+            (
+                dest_chain_name.to_owned(),
+                src_chain_name.to_owned(),
+                dest_port.to_owned(),
+            ),
+            ChannelInfo::from(&dest_channel),
+        );
+
+        let src_pair = (src_chain_name.to_owned(), dest_chain_name.to_owned());
+        let dest_pair = (dest_chain_name.to_owned(), src_chain_name.to_owned());
+
+        // Transfers use the connection of the most recent transfer channel
+        if src_port == TRANSFER_PORT && dest_port == TRANSFER_PORT {
+            self.connection_ids
+                .insert(src_pair, created.src_connection_id.clone());
+            self.connection_ids
+                .insert(dest_pair, created.dest_connection_id.clone());
+        } else {
+            self.connection_ids
+                .entry(src_pair)
+                .or_insert_with(|| created.src_connection_id.clone());
+            self.connection_ids
+                .entry(dest_pair)
+                .or_insert_with(|| created.dest_connection_id.clone());
+        }
+
+        Ok(created)
+    }
+
+    /// Starts the relayer relaying all paths.
+    pub fn start_relayer(&mut self) -> Result<(), Error> {
+        self.relayer_action("start-relayer")
+    }
+
+    /// Stops the relayer. Packets may still be relayed one path at a time with `relay_packets`.
+    pub fn stop_relayer(&mut self) -> Result<(), Error> {
+        self.relayer_action("stop-relayer")
+    }
+
+    /// Relays all pending packets and acknowledgements over the transfer channel from src to dest.
+    pub fn relay_packets(
+        &mut self,
+        src_chain_name: &str,
+        dest_chain_name: &str,
+    ) -> Result<(), Error> {
+        let (path, channel) = self.relayer_path(src_chain_name, dest_chain_name)?;
+
+        self.relayer_exec(src_chain_name, &format!("rly tx flush {path} {channel}"))?;
+
+        Ok(())
+    }
+
+    /// Gets the packets sent over the transfer channel from src to dest that have not been received.
+    pub fn pending_packets(
+        &self,
+        src_chain_name: &str,
+        dest_chain_name: &str,
+    ) -> Result<PendingPackets, Error> {
+        let (path, channel) = self.relayer_path(src_chain_name, dest_chain_name)?;
+
+        self.relayer_query(
+            src_chain_name,
+            &format!("rly q unrelayed-packets {path} {channel}"),
+        )
+    }
+
+    /// Gets the packets received over the transfer channel from src to dest that have not been acknowledged.
+    pub fn pending_acks(
+        &self,
+        src_chain_name: &str,
+        dest_chain_name: &str,
+    ) -> Result<PendingPackets, Error> {
+        let (path, channel) = self.relayer_path(src_chain_name, dest_chain_name)?;
+
+        self.relayer_query(
+            src_chain_name,
+            &format!("rly q unrelayed-acknowledgements {path} {channel}"),
+        )
+    }
+
+    /// Gets the name of the relayer path between two chains, and the src chain's transfer channel on it.
+    fn relayer_path(
+        &self,
+        src_chain_name: &str,
+        dest_chain_name: &str,
+    ) -> Result<(String, String), Error> {
+        let channel = self
+            .get_transfer_channels()
+            .src(src_chain_name)
+            .dest(dest_chain_name)
+            .try_get()?;
+
+        Ok((
+            self.relayer_path_name(src_chain_name, dest_chain_name)?,
+            channel,
+        ))
+    }
+
+    /// Gets the name of the relayer path between two chains from their ibc_paths in logs.json.
+    pub(crate) fn relayer_path_name(
+        &self,
+        src_chain_name: &str,
+        dest_chain_name: &str,
+    ) -> Result<String, Error> {
+        let ibc_paths = |chain_name: &str| {
+            let chain_id = &self.get_chain(chain_name).rb.chain_id;
+
+            self.log_file
+                .chains
+                .iter()
+                .find(|chain| &chain.chain_id == chain_id)
+                .map(|chain| chain.ibc_paths.clone())
+                .unwrap_or_default()
+        };
+
+        let dest_paths = ibc_paths(dest_chain_name);
+
+        ibc_paths(src_chain_name)
+            .into_iter()
+            .find(|path| dest_paths.contains(path))
+            .ok_or(Error::MissingContextVariable(format!(
+                "ibc_path::{src_chain_name}-{dest_chain_name}"
+            )))
+    }
+
+    /// Gets the channels from src to dest known by the relayer.
+    fn relayer_channels(
+        &self,
+        src_chain_name: &str,
+        dest_chain_name: &str,
+    ) -> Result<Vec<QueryChannel>, Error> {
+        let src_chain_id = &self.get_chain(src_chain_name).rb.chain_id;
+        let dest_chain_id = &self.get_chain(dest_chain_name).rb.chain_id;
+
+        let res = self.relayer_exec(
+            src_chain_name,
+            &format!("rly q channels {src_chain_id} {dest_chain_id}"),
+        )?;
+
+        Ok(res["text"]
+            .as_str()
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+
+    pub(crate) fn relayer_exec(&self, chain_name: &str, cmd: &str) -> Result<Value, Error> {
+        let relayer = Relayer::new(&self.get_chain(chain_name).rb);
+
+        Ok(relayer.execute(cmd, true)?)
+    }
+
+    fn relayer_query(&self, chain_name: &str, cmd: &str) -> Result<PendingPackets, Error> {
+        let res = self.relayer_exec(chain_name, cmd)?;
+
+        // The relayer may log before printing the result on the last line
+        let text = res["text"]
+            .as_str()
+            .and_then(|text| text.lines().rev().find(|line| !line.trim().is_empty()))
+            .ok_or(Error::ContainerCmd(cmd.to_owned()))?;
+
+        Ok(serde_json::from_str(text)?)
+    }
+
+    fn relayer_action(&self, action: &str) -> Result<(), Error> {
+        // Any chain will allow us to control the relayer,
+        // so select any chain we can get the API URL from
+        let chain = self
+            .chains
+            .values()
+            .next()
+            .ok_or(Error::MissingContextVariable(String::from("chain")))?;
+
+        // chain_id does not matter, since there is one relayer running
+        reqwest::blocking::Client::default()
+            .post(&chain.rb.api)
+            .json(&serde_json::json!({ "chain_id": chain.rb.chain_id, "action": action }))
+            .send()?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
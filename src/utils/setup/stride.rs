@@ -1,9 +1,19 @@
-use localic_std::{errors::LocalError, transactions::ChainRequestBuilder};
+use localic_std::transactions::ChainRequestBuilder;
 use log::info;
 use serde_json::Value;
 
 use crate::{
-    error::Error, utils::test_context::TestContext, ADMIN_KEY, DEFAULT_KEY, STRIDE_CHAIN_NAME,
+    error::Error,
+    types::tx::{Gas, TxOptions, TxReceipt},
+    utils::test_context::TestContext,
+    ADMIN_KEY, DEFAULT_KEY, STRIDE_CHAIN_NAME,
+};
+
+/// The default options of stride txs, whose gas is estimated.
+const STRIDE_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Auto),
+    gas_adjustment: Some(1.3),
+    ..TxOptions::EMPTY
 };
 
 /// A tx liquid staking.
@@ -11,6 +21,7 @@ pub struct LiquidStakeTxBuilder<'a> {
     key: &'a str,
     denom: Option<&'a str>,
     amount: Option<u128>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -33,14 +44,22 @@ impl<'a> LiquidStakeTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_liquid_stake(
             self.key,
             self.denom
                 .ok_or(Error::MissingBuilderParam(String::from("denom")))?,
             self.amount
                 .ok_or(Error::MissingBuilderParam(String::from("amount")))?,
+            &self.tx_options,
         )
     }
 }
@@ -51,6 +70,7 @@ impl TestContext {
             key: DEFAULT_KEY,
             denom: None,
             amount: None,
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -114,17 +134,16 @@ impl TestContext {
         ibc_denom: &str,
         channel_id: &str,
         from_key: &str,
-    ) -> Result<Value, LocalError> {
+    ) -> Result<Value, Error> {
+        let flags = self
+            .get_chain(STRIDE_CHAIN_NAME)
+            .tx_flags(&TxOptions::EMPTY, &STRIDE_TX_OPTIONS)?;
         let cmd = format!(
-            "tx stakeibc register-host-zone {} {} {} {} {} 1 --from={} --gas auto --gas-adjustment 1.3 --output=json",
-            connection_id,
-            host_denom,
-            bech_32_prefix,
-            ibc_denom,
-            channel_id,
-            from_key,
+            "tx stakeibc register-host-zone {} {} {} {} {} 1 --from={}{} --output=json",
+            connection_id, host_denom, bech_32_prefix, ibc_denom, channel_id, from_key, flags,
         );
-        rb.tx(&cmd, true)
+
+        Ok(rb.tx(&cmd, true)?)
     }
 
     fn tx_liquid_stake(
@@ -132,13 +151,24 @@ impl TestContext {
         sender_key: &str,
         liquid_stake_denom: &str,
         liquid_stake_amount: u128,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let stride = self.get_chain(STRIDE_CHAIN_NAME);
         let cmd = format!(
-            "tx stakeibc liquid-stake {} {} --from={} --gas auto --gas-adjustment 1.3 --output=json",
-            liquid_stake_amount, liquid_stake_denom, sender_key,
+            "tx stakeibc liquid-stake {} {} --from={}{} --output=json",
+            liquid_stake_amount,
+            liquid_stake_denom,
+            sender_key,
+            stride.tx_flags(tx_options, &STRIDE_TX_OPTIONS)?,
         );
-        self.get_chain(STRIDE_CHAIN_NAME).rb.tx(&cmd, true)?;
-
-        Ok(())
+        let receipt = stride.rb.tx(&cmd, true)?;
+
+        self.guard_tx_errors(
+            STRIDE_CHAIN_NAME,
+            receipt
+                .get("txhash")
+                .and_then(|receipt| receipt.as_str())
+                .ok_or(Error::TxMissingLogs)?,
+        )
     }
 }
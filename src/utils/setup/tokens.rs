@@ -1,13 +1,31 @@
 use super::super::{
-    super::{error::Error, DEFAULT_KEY, NEUTRON_CHAIN_NAME},
+    super::{
+        error::Error,
+        types::tx::{Gas, TxOptions, TxReceipt},
+        DEFAULT_KEY, NEUTRON_CHAIN_NAME,
+    },
     test_context::TestContext,
 };
 
+/// The default options of tokenfactory denom creation.
+const CREATE_DENOM_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Limit(10000000)),
+    fee_amount: Some(25000),
+    ..TxOptions::EMPTY
+};
+
+/// The default options of tokenfactory mints.
+const MINT_TX_OPTIONS: TxOptions = TxOptions {
+    fee_amount: Some(500),
+    ..TxOptions::EMPTY
+};
+
 /// A tx creating a tokenfactory token.
 pub struct CreateTokenFactoryTokenTxBuilder<'a> {
     key: Option<&'a str>,
     chain_name: Option<String>,
     subdenom: Option<&'a str>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -30,8 +48,15 @@ impl<'a> CreateTokenFactoryTokenTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_create_tokenfactory_token(
             self.chain_name
                 .as_ref()
@@ -40,6 +65,7 @@ impl<'a> CreateTokenFactoryTokenTxBuilder<'a> {
                 .ok_or(Error::MissingBuilderParam(String::from("key")))?,
             self.subdenom
                 .ok_or(Error::MissingBuilderParam(String::from("subdenom")))?,
+            &self.tx_options,
         )
     }
 }
@@ -51,6 +77,7 @@ pub struct MintTokenFactoryTokenTxBuilder<'a> {
     denom: Option<&'a str>,
     amount: Option<u128>,
     recipient_addr: Option<&'a str>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -85,8 +112,15 @@ impl<'a> MintTokenFactoryTokenTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_mint_tokenfactory_token(
             self.chain_name
                 .as_ref()
@@ -98,6 +132,7 @@ impl<'a> MintTokenFactoryTokenTxBuilder<'a> {
             self.amount
                 .ok_or(Error::MissingBuilderParam(String::from("amount")))?,
             self.recipient_addr,
+            &self.tx_options,
         )
     }
 }
@@ -108,6 +143,7 @@ impl TestContext {
             key: Some(DEFAULT_KEY),
             chain_name: Some(NEUTRON_CHAIN_NAME.to_owned()),
             subdenom: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -118,13 +154,13 @@ impl TestContext {
         chain_name: &str,
         key: &str,
         subdenom: &str,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         let chain = self.get_chain(chain_name);
-        let fee_denom = chain.native_denom.as_str();
+        let flags = chain.tx_flags(tx_options, &CREATE_DENOM_TX_OPTIONS)?;
 
         let receipt = chain.rb.tx(
-            format!("tx tokenfactory create-denom {subdenom} --from {key} --fees 25000{fee_denom} --gas 10000000")
-                .as_str(),
+            format!("tx tokenfactory create-denom {subdenom} --from {key}{flags}").as_str(),
             true,
         )?;
 
@@ -134,9 +170,7 @@ impl TestContext {
                 .get("txhash")
                 .and_then(|receipt| receipt.as_str())
                 .ok_or(Error::TxMissingLogs)?,
-        )?;
-
-        Ok(())
+        )
     }
 
     /// Creates a builder for a tx minting a quantity of a tokenfactory token on the specified chain.
@@ -147,10 +181,12 @@ impl TestContext {
             denom: Default::default(),
             amount: Default::default(),
             recipient_addr: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn tx_mint_tokenfactory_token(
         &mut self,
         chain_name: &str,
@@ -158,30 +194,18 @@ impl TestContext {
         denom: &str,
         amount: u128,
         recipient: Option<&str>,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         let chain = self.get_chain(chain_name);
-        let fee_denom = chain.native_denom.as_str();
-
-        if let Some(recipient) = recipient {
-            let receipt = chain.rb.tx(
-                format!("tx tokenfactory mint {amount}{denom} {recipient} --from {key} --fees 500{fee_denom}")
-                    .as_str(),
-                true,
-            )?;
-
-            self.guard_tx_errors(
-                chain_name,
-                receipt
-                    .get("txhash")
-                    .and_then(|receipt| receipt.as_str())
-                    .ok_or(Error::TxMissingLogs)?,
-            )?;
-
-            return Ok(());
-        }
+        let flags = chain.tx_flags(tx_options, &MINT_TX_OPTIONS)?;
+
+        // Tokens are minted to the signer unless a recipient is given
+        let recipient_part = recipient
+            .map(|recipient| format!(" {recipient}"))
+            .unwrap_or_default();
 
         let receipt = chain.rb.tx(
-            format!("tx tokenfactory mint {amount}{denom} --from {key} --fees 500{fee_denom}")
+            format!("tx tokenfactory mint {amount}{denom}{recipient_part} --from {key}{flags}")
                 .as_str(),
             true,
         )?;
@@ -192,8 +216,6 @@ impl TestContext {
                 .get("txhash")
                 .and_then(|receipt| receipt.as_str())
                 .ok_or(Error::TxMissingLogs)?,
-        )?;
-
-        Ok(())
+        )
     }
 }
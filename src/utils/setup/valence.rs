@@ -1,9 +1,12 @@
 use super::super::{
     super::{
         error::Error,
-        types::contract::{AuctionStrategy, ChainHaltConfig, MinAmount, PriceFreshnessStrategy},
+        types::{
+            contract::{AuctionStrategy, ChainHaltConfig, MinAmount, PriceFreshnessStrategy},
+            tx::{Gas, TxOptions, TxReceipt},
+        },
         AUCTIONS_MANAGER_CONTRACT_NAME, AUCTION_CONTRACT_NAME, DEFAULT_AUCTION_LABEL, DEFAULT_KEY,
-        NEUTRON_CHAIN_ADMIN_ADDR, NEUTRON_CHAIN_NAME, PRICE_ORACLE_NAME,
+        NEUTRON_CHAIN_NAME, PRICE_ORACLE_NAME,
     },
     test_context::TestContext,
 };
@@ -11,12 +14,25 @@ use cosmwasm_std::Decimal;
 use localic_std::modules::cosmwasm::CosmWasm;
 use serde_json::Value;
 
+/// The default options of txs administering auctions and the price oracle.
+const AUCTION_ADMIN_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Limit(2000000)),
+    ..TxOptions::EMPTY
+};
+
+/// The default options of txs funding and starting auctions.
+const AUCTION_TX_OPTIONS: TxOptions = TxOptions {
+    gas: Some(Gas::Limit(1000000)),
+    ..TxOptions::EMPTY
+};
+
 /// A tx creating an auctions manager.
 pub struct CreateAuctionsManagerTxBuilder<'a> {
     key: &'a str,
     chain: &'a str,
     min_auction_amount: &'a [(&'a str, MinAmount)],
-    server_addr: &'a str,
+    server_addr: Option<&'a str>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -42,19 +58,28 @@ impl<'a> CreateAuctionsManagerTxBuilder<'a> {
         self
     }
 
+    /// Sets the address of the auctions server. Defaults to the address of the signing key.
     pub fn with_server_addr(&mut self, addr: &'a str) -> &mut Self {
-        self.server_addr = addr;
+        self.server_addr = Some(addr);
+
+        self
+    }
+
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
 
         self
     }
 
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_create_auctions_manager(
             self.key,
             self.chain,
             self.min_auction_amount,
             self.server_addr,
+            &self.tx_options,
         )
     }
 }
@@ -69,6 +94,7 @@ pub struct CreateAuctionTxBuilder<'a> {
     price_freshness_strategy: PriceFreshnessStrategy,
     label: &'a str,
     amount_offer_asset: Option<u128>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -130,8 +156,15 @@ impl<'a> CreateAuctionTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_create_auction(
             self.key,
             self.chain,
@@ -149,6 +182,7 @@ impl<'a> CreateAuctionTxBuilder<'a> {
                 .ok_or(Error::MissingBuilderParam(String::from(
                     "amount_offer_asset",
                 )))?,
+            &self.tx_options,
         )
     }
 }
@@ -159,6 +193,7 @@ pub struct FundAuctionTxBuilder<'a> {
     offer_asset: Option<&'a str>,
     ask_asset: Option<&'a str>,
     amt_offer_asset: Option<u128>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -193,8 +228,15 @@ impl<'a> FundAuctionTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_fund_auction(
             self.key,
             self.chain,
@@ -208,6 +250,7 @@ impl<'a> FundAuctionTxBuilder<'a> {
                 .ok_or(Error::MissingBuilderParam(String::from(
                     "amount_offer_asset",
                 )))?,
+            &self.tx_options,
         )
     }
 }
@@ -218,6 +261,7 @@ pub struct StartAuctionTxBuilder<'a> {
     offer_asset: Option<&'a str>,
     ask_asset: Option<&'a str>,
     end_block_delta: Option<u128>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -252,8 +296,15 @@ impl<'a> StartAuctionTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_start_auction(
             self.key,
             self.chain,
@@ -265,6 +316,7 @@ impl<'a> StartAuctionTxBuilder<'a> {
                 self.ask_asset
                     .ok_or(Error::MissingBuilderParam(String::from("pair")))?,
             ),
+            &self.tx_options,
         )
     }
 }
@@ -274,6 +326,7 @@ pub struct MigrateAuctionTxBuilder<'a> {
     chain: &'a str,
     offer_asset: Option<&'a str>,
     ask_asset: Option<&'a str>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -302,8 +355,15 @@ impl<'a> MigrateAuctionTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_migrate_auction(
             self.key,
             self.chain,
@@ -313,6 +373,7 @@ impl<'a> MigrateAuctionTxBuilder<'a> {
                 self.ask_asset
                     .ok_or(Error::MissingBuilderParam(String::from("pair")))?,
             ),
+            &self.tx_options,
         )
     }
 }
@@ -322,6 +383,7 @@ pub struct CreatePriceOracleTxBuilder<'a> {
     chain: &'a str,
     seconds_allow_manual_change: u64,
     seconds_auction_prices_fresh: u64,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -350,13 +412,21 @@ impl<'a> CreatePriceOracleTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_create_price_oracle(
             self.key,
             self.chain,
             self.seconds_allow_manual_change,
             self.seconds_auction_prices_fresh,
+            &self.tx_options,
         )
     }
 }
@@ -364,6 +434,7 @@ impl<'a> CreatePriceOracleTxBuilder<'a> {
 pub struct UpdateAuctionOracleTxBuilder<'a> {
     key: &'a str,
     chain: &'a str,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -380,9 +451,17 @@ impl<'a> UpdateAuctionOracleTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
-        self.test_ctx.tx_update_auction_oracle(self.key, self.chain)
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
+        self.test_ctx
+            .tx_update_auction_oracle(self.key, self.chain, &self.tx_options)
     }
 }
 
@@ -392,6 +471,7 @@ pub struct ManualOraclePriceUpdateTxBuilder<'a> {
     offer_asset: Option<&'a str>,
     ask_asset: Option<&'a str>,
     price: Option<Decimal>,
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -426,8 +506,15 @@ impl<'a> ManualOraclePriceUpdateTxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the transaction.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_manual_oracle_price_update(
             self.key,
             self.chain,
@@ -437,6 +524,7 @@ impl<'a> ManualOraclePriceUpdateTxBuilder<'a> {
                 .ok_or(Error::MissingBuilderParam(String::from("ask_asset")))?,
             self.price
                 .ok_or(Error::MissingBuilderParam(String::from("price")))?,
+            &self.tx_options,
         )
     }
 }
@@ -447,7 +535,8 @@ impl TestContext {
             key: DEFAULT_KEY,
             chain: NEUTRON_CHAIN_NAME,
             min_auction_amount: &[],
-            server_addr: NEUTRON_CHAIN_ADMIN_ADDR,
+            server_addr: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -459,8 +548,14 @@ impl TestContext {
         sender_key: &str,
         chain: &str,
         min_auction_amount: impl AsRef<[(&'a str, MinAmount)]>,
-        server_addr: impl AsRef<str>,
-    ) -> Result<(), Error> {
+        server_addr: Option<&str>,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
+        let server_addr = match server_addr {
+            Some(server_addr) => server_addr.to_owned(),
+            None => self.address_of(chain, sender_key)?,
+        };
+
         let mut contract_a: CosmWasm = self
             .get_contract()
             .contract(AUCTIONS_MANAGER_CONTRACT_NAME)
@@ -480,22 +575,26 @@ impl TestContext {
             serde_json::json!({
                 "auction_code_id": auction_code_id,
                 "min_auction_amount": min_auction_amount.as_ref(),
-                "server_addr": server_addr.as_ref(),
+                "server_addr": server_addr,
             })
             .to_string()
             .as_str(),
             AUCTIONS_MANAGER_CONTRACT_NAME,
             None,
-            "",
+            &self
+                .get_chain(chain)
+                .tx_flags(tx_options, &TxOptions::EMPTY)?,
         )?;
 
+        let receipt = self.guard_tx_errors(chain, contract.tx_hash.as_str())?;
+
         let local_chain = self.get_mut_chain(chain);
 
         local_chain
             .contract_addrs
             .insert(AUCTIONS_MANAGER_CONTRACT_NAME.to_owned(), contract.address);
 
-        Ok(())
+        Ok(receipt)
     }
 
     pub fn build_tx_create_price_oracle(&mut self) -> CreatePriceOracleTxBuilder {
@@ -504,6 +603,7 @@ impl TestContext {
             chain: NEUTRON_CHAIN_NAME,
             seconds_allow_manual_change: 0,
             seconds_auction_prices_fresh: 100000000000,
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -516,7 +616,8 @@ impl TestContext {
         chain: &str,
         seconds_allow_manual_change: u64,
         seconds_auction_prices_fresh: u64,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         let auctions_manager: CosmWasm = self.get_auctions_manager().src(chain).get_cw();
         let auctions_manager_addr =
             auctions_manager
@@ -541,16 +642,20 @@ impl TestContext {
             .as_str(),
             PRICE_ORACLE_NAME,
             None,
-            "",
+            &self
+                .get_chain(chain)
+                .tx_flags(tx_options, &TxOptions::EMPTY)?,
         )?;
 
-        let chain = self.get_mut_chain(chain);
+        let receipt = self.guard_tx_errors(chain, contract.tx_hash.as_str())?;
+
+        let local_chain = self.get_mut_chain(chain);
 
-        chain
+        local_chain
             .contract_addrs
             .insert(PRICE_ORACLE_NAME.to_owned(), contract.address);
 
-        Ok(())
+        Ok(receipt)
     }
 
     /// Creates an auction on Neutron. Requires that an auction manager has already been deployed.
@@ -574,6 +679,7 @@ impl TestContext {
             },
             label: DEFAULT_AUCTION_LABEL,
             amount_offer_asset: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -590,7 +696,8 @@ impl TestContext {
         price_freshness_strategy: PriceFreshnessStrategy,
         label: impl AsRef<str>,
         amount_denom_a: u128,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         // The auctions manager for this deployment
         let contract_a = self.get_auctions_manager().src(chain).get_cw();
         let denom_a = pair.0.as_ref();
@@ -612,7 +719,12 @@ impl TestContext {
             }})
             .to_string()
             .as_str(),
-            format!("--amount {amount_denom_a}{denom_a} --gas 2000000").as_str(),
+            format!(
+                "--amount {amount_denom_a}{denom_a}{}",
+                self.get_chain(chain)
+                    .tx_flags(tx_options, &AUCTION_ADMIN_TX_OPTIONS)?
+            )
+            .as_str(),
         )?;
 
         log::debug!(
@@ -622,9 +734,7 @@ impl TestContext {
             receipt
         );
 
-        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())?;
-
-        Ok(())
+        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())
     }
 
     /// Creates an auction on Neutron. Requires that an auction manager has already been deployed.
@@ -634,6 +744,7 @@ impl TestContext {
             chain: NEUTRON_CHAIN_NAME,
             offer_asset: Default::default(),
             ask_asset: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -644,7 +755,8 @@ impl TestContext {
         sender_key: &str,
         chain: &str,
         pair: (TDenomA, TDenomB),
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         // The auctions manager for this deployment
         let contract_a = self.get_auctions_manager().src(chain).get_cw();
         let code_id = self
@@ -672,7 +784,9 @@ impl TestContext {
             }})
             .to_string()
             .as_str(),
-            "--gas 2000000",
+            &self
+                .get_chain(chain)
+                .tx_flags(tx_options, &AUCTION_ADMIN_TX_OPTIONS)?,
         )?;
 
         log::debug!(
@@ -682,9 +796,7 @@ impl TestContext {
             receipt
         );
 
-        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())?;
-
-        Ok(())
+        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())
     }
 
     /// Creates a builder setting the oracle address on the auctions manager on neutron.
@@ -692,11 +804,17 @@ impl TestContext {
         UpdateAuctionOracleTxBuilder {
             key: DEFAULT_KEY,
             chain: NEUTRON_CHAIN_NAME,
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
 
-    fn tx_update_auction_oracle(&mut self, sender_key: &str, chain: &str) -> Result<(), Error> {
+    fn tx_update_auction_oracle(
+        &mut self,
+        sender_key: &str,
+        chain: &str,
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         // The auctions manager for this deployment
         let contract_a = self.get_auctions_manager().src(chain).get_cw();
         let local_chain = self.get_chain(chain);
@@ -715,12 +833,12 @@ impl TestContext {
             }})
             .to_string()
             .as_str(),
-            "--gas 2000000",
+            &self
+                .get_chain(chain)
+                .tx_flags(tx_options, &AUCTION_ADMIN_TX_OPTIONS)?,
         )?;
 
-        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())?;
-
-        Ok(())
+        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())
     }
 
     /// Creates a builder setting the oracle address on the auctions manager on neutron.
@@ -731,6 +849,7 @@ impl TestContext {
             offer_asset: Default::default(),
             ask_asset: Default::default(),
             price: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -742,7 +861,8 @@ impl TestContext {
         offer_asset: &str,
         ask_asset: &str,
         price: Decimal,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         // The auctions manager for this deployment
         let oracle = self.get_price_oracle().src(chain).get_cw();
 
@@ -757,12 +877,12 @@ impl TestContext {
             })
             .to_string()
             .as_str(),
-            "--gas 2000000",
+            &self
+                .get_chain(chain)
+                .tx_flags(tx_options, &AUCTION_ADMIN_TX_OPTIONS)?,
         )?;
 
-        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())?;
-
-        Ok(())
+        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())
     }
 
     /// Sends the specified amount of funds to an auction.
@@ -773,6 +893,7 @@ impl TestContext {
             offer_asset: Default::default(),
             ask_asset: Default::default(),
             amt_offer_asset: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -784,7 +905,8 @@ impl TestContext {
         chain: &str,
         pair: (TDenomA, TDenomB),
         amt_offer_asset: u128,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         let manager = self.get_auctions_manager().src(chain).get_cw();
 
         let denom_a = pair.0.as_ref();
@@ -798,12 +920,15 @@ impl TestContext {
             })
             .to_string()
             .as_str(),
-            format!("--amount {amt_offer_asset}{denom_a} --gas 1000000").as_str(),
+            format!(
+                "--amount {amt_offer_asset}{denom_a}{}",
+                self.get_chain(chain)
+                    .tx_flags(tx_options, &AUCTION_TX_OPTIONS)?
+            )
+            .as_str(),
         )?;
 
-        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())?;
-
-        Ok(())
+        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())
     }
 
     /// Builds a transaction to start the auction.
@@ -814,6 +939,7 @@ impl TestContext {
             offer_asset: Default::default(),
             ask_asset: Default::default(),
             end_block_delta: Default::default(),
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -825,7 +951,8 @@ impl TestContext {
         chain: &str,
         end_blocks: u128,
         pair: (TDenomA, TDenomB),
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         let manager = self.get_auctions_manager().src(chain).get_cw();
         let local_chain = self.get_chain(chain);
 
@@ -862,11 +989,11 @@ impl TestContext {
             })
             .to_string()
             .as_str(),
-            "--gas 1000000",
+            &self
+                .get_chain(chain)
+                .tx_flags(tx_options, &AUCTION_TX_OPTIONS)?,
         )?;
 
-        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())?;
-
-        Ok(())
+        self.guard_tx_errors(chain, receipt.tx_hash.ok_or(Error::TxMissingLogs)?.as_str())
     }
 }
@@ -1,5 +1,9 @@
 use super::super::{
-    super::{error::Error, DEFAULT_KEY, NEUTRON_CHAIN_NAME},
+    super::{
+        error::Error,
+        types::tx::{TxOptions, TxReceipt},
+        DEFAULT_KEY, NEUTRON_CHAIN_NAME,
+    },
     test_context::TestContext,
 };
 use cosmwasm_std::Coin;
@@ -20,6 +24,7 @@ pub struct Instantiate2TxBuilder<'a> {
     fix_msg: Option<bool>,
     flags: Option<&'a str>,
 
+    tx_options: TxOptions,
     test_ctx: &'a mut TestContext,
 }
 
@@ -85,8 +90,15 @@ impl<'a> Instantiate2TxBuilder<'a> {
         self
     }
 
+    /// Sets the gas, fees, and other flags of the tx.
+    pub fn with_tx_options(&mut self, tx_options: TxOptions) -> &mut Self {
+        self.tx_options = tx_options;
+
+        self
+    }
+
     /// Sends the built instantiate 2 tx.
-    pub fn send(&mut self) -> Result<(), Error> {
+    pub fn send(&mut self) -> Result<TxReceipt, Error> {
         self.test_ctx.tx_instantiate2(
             self.key,
             self.chain_name,
@@ -98,6 +110,7 @@ impl<'a> Instantiate2TxBuilder<'a> {
             self.salt.as_ref().expect("missing builder param salt"),
             self.fix_msg,
             self.flags,
+            &self.tx_options,
         )
     }
 }
@@ -115,6 +128,7 @@ impl TestContext {
             salt: None,
             fix_msg: None,
             flags: None,
+            tx_options: Default::default(),
             test_ctx: self,
         }
     }
@@ -132,7 +146,8 @@ impl TestContext {
         salt: &str,
         fix_msg: Option<bool>,
         flags: Option<&str>,
-    ) -> Result<(), Error> {
+        tx_options: &TxOptions,
+    ) -> Result<TxReceipt, Error> {
         let chain = self.get_chain(chain_name);
 
         // Optional flags
@@ -146,9 +161,10 @@ impl TestContext {
             .map(|fix_msg| format!("--fix_msg {fix_msg} "))
             .unwrap_or_default();
         let flags_part = flags.map(|flags| format!(" {flags}")).unwrap_or_default();
+        let tx_flags = chain.tx_flags(tx_options, &TxOptions::EMPTY)?;
 
         let receipt = chain.rb.tx(
-            &format!("tx wasm instantiate2 {code_id} {msg} {salt} --label {label} {admin_part}{amt_part}{fix_msg_part}--from {key}{flags_part}"),
+            &format!("tx wasm instantiate2 {code_id} {msg} {salt} --label {label} {admin_part}{amt_part}{fix_msg_part}--from {key}{flags_part}{tx_flags}"),
             true,
         )?;
 
@@ -158,8 +174,6 @@ impl TestContext {
                 .get("txhash")
                 .and_then(|receipt| receipt.as_str())
                 .ok_or(Error::TxMissingLogs)?,
-        )?;
-
-        Ok(())
+        )
     }
 }
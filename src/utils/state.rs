@@ -0,0 +1,295 @@
+use super::{
+    super::{error::Error, types::ibc::ChannelInfo},
+    test_context::TestContext,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::ErrorKind,
+    path::Path,
+};
+
+/// A serializable snapshot of the state accumulated by a TestContext during
+/// a local-ic session.
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct ContextState {
+    /// The start time of the local-ic session the state was recorded in
+    pub start_time: u64,
+    /// Maps a chain name to the contracts stored and instantiated on it
+    pub chains: HashMap<String, ChainState>,
+    pub channels: Vec<ChannelEntry>,
+    pub connection_ids: Vec<ChainPairEntry>,
+    pub ibc_denoms: Vec<ChainPairEntry>,
+}
+
+/// Contracts stored and instantiated on a chain.
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct ChainState {
+    pub contract_codes: HashMap<String, u64>,
+    pub contract_addrs: HashMap<String, String>,
+}
+
+/// A channel from src_chain to dest_chain on a port of src_chain.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChannelEntry {
+    pub src: String,
+    pub dest: String,
+    pub port: String,
+    pub channel: ChannelInfo,
+}
+
+/// An entry in a map keyed by a (src_chain, dest_chain) pair.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChainPairEntry {
+    pub src: String,
+    pub dest: String,
+    pub value: String,
+}
+
+fn to_entries(map: &HashMap<(String, String), String>) -> Vec<ChainPairEntry> {
+    map.iter()
+        .map(|((src, dest), value)| ChainPairEntry {
+            src: src.clone(),
+            dest: dest.clone(),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+/// Inserts entries into the map, without overwriting values already present.
+fn extend_from_entries(map: &mut HashMap<(String, String), String>, entries: Vec<ChainPairEntry>) {
+    for ChainPairEntry { src, dest, value } in entries {
+        map.entry((src, dest)).or_insert(value);
+    }
+}
+
+impl TestContext {
+    /// Gets a snapshot of the contracts, channels, connections, and denoms known to the context.
+    pub fn state(&self) -> ContextState {
+        ContextState {
+            start_time: self.log_file.start_time,
+            chains: self
+                .chains
+                .iter()
+                .map(|(name, chain)| {
+                    (
+                        name.clone(),
+                        ChainState {
+                            contract_codes: chain.contract_codes.clone(),
+                            contract_addrs: chain.contract_addrs.clone(),
+                        },
+                    )
+                })
+                .collect(),
+            channels: self
+                .channels
+                .iter()
+                .map(|((src, dest, port), channel)| ChannelEntry {
+                    src: src.clone(),
+                    dest: dest.clone(),
+                    port: port.clone(),
+                    channel: channel.clone(),
+                })
+                .collect(),
+            connection_ids: to_entries(&self.connection_ids),
+            ibc_denoms: to_entries(&self.ibc_denoms),
+        }
+    }
+
+    /// Writes a snapshot of the context's state to a JSON file, which may be restored
+    /// by later contexts in the same local-ic session with `TestContextBuilder::with_state_file`.
+    pub fn save_state(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let f = File::create(path)?;
+        serde_json::to_writer_pretty(f, &self.state())?;
+
+        Ok(())
+    }
+
+    /// Restores the context's state from a JSON file written by `save_state`,
+    /// keeping entries already known to the context. Returns false without modifying the context if the file does not exist,
+    /// or was recorded in a different local-ic session.
+    pub fn restore_state(&mut self, path: impl AsRef<Path>) -> Result<bool, Error> {
+        let path = path.as_ref();
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                info!(
+                    "No state file found at {}, skipping restore",
+                    path.display()
+                );
+
+                return Ok(false);
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let state: ContextState = serde_json::from_str(&contents)?;
+
+        if state.start_time != self.log_file.start_time {
+            info!(
+                "State file {} is from a previous local-ic session, skipping restore",
+                path.display()
+            );
+
+            return Ok(false);
+        }
+
+        for (name, chain_state) in state.chains {
+            let Some(chain) = self.chains.get_mut(&name) else {
+                continue;
+            };
+
+            for (id, code_id) in chain_state.contract_codes {
+                chain.contract_codes.entry(id).or_insert(code_id);
+            }
+
+            for (name, addr) in chain_state.contract_addrs {
+                chain.contract_addrs.entry(name).or_insert(addr);
+            }
+        }
+
+        for ChannelEntry {
+            src,
+            dest,
+            port,
+            channel,
+        } in state.channels
+        {
+            self.channels.entry((src, dest, port)).or_insert(channel);
+        }
+
+        extend_from_entries(&mut self.connection_ids, state.connection_ids);
+        extend_from_entries(&mut self.ibc_denoms, state.ibc_denoms);
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::{
+            testing::{mock_context_builder, MockLocalIc},
+            NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, TRANSFER_PORT,
+        },
+        *,
+    };
+    use std::{env, path::PathBuf};
+
+    fn state_file(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("localic_utils_{}_{name}.json", std::process::id()))
+    }
+
+    fn context_with_state(mock: &MockLocalIc) -> TestContext {
+        let mut ctx = mock_context_builder(mock)
+            .with_transfer_channel_id(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, "channel-0")
+            .with_connection_id(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, "connection-0")
+            .build()
+            .unwrap();
+
+        let neutron = ctx.get_mut_chain(NEUTRON_CHAIN_NAME);
+        neutron.contract_codes.insert(String::from("factory"), 1);
+        neutron
+            .contract_addrs
+            .insert(String::from("factory"), String::from("neutron1factory"));
+
+        ctx
+    }
+
+    #[test]
+    fn test_restore_state_round_trip() {
+        let mock = MockLocalIc::start().unwrap();
+        let path = state_file("round_trip");
+        context_with_state(&mock).save_state(&path).unwrap();
+
+        let mut ctx = mock_context_builder(&mock).build().unwrap();
+
+        assert!(ctx.restore_state(&path).unwrap());
+
+        let neutron = ctx.get_chain(NEUTRON_CHAIN_NAME);
+        assert_eq!(neutron.contract_codes.get("factory"), Some(&1));
+        assert_eq!(
+            neutron.contract_addrs.get("factory").map(String::as_str),
+            Some("neutron1factory")
+        );
+        assert_eq!(
+            ctx.get_transfer_channels()
+                .src(NEUTRON_CHAIN_NAME)
+                .dest(OSMOSIS_CHAIN_NAME)
+                .get(),
+            "channel-0"
+        );
+        assert_eq!(
+            ctx.get_connections()
+                .src(NEUTRON_CHAIN_NAME)
+                .dest(OSMOSIS_CHAIN_NAME)
+                .get(),
+            "connection-0"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_restore_state_rejects_other_session() {
+        let mock = MockLocalIc::start().unwrap();
+        let path = state_file("other_session");
+
+        let mut saved = context_with_state(&mock);
+        saved.log_file.start_time += 1;
+        saved.save_state(&path).unwrap();
+
+        let mut ctx = mock_context_builder(&mock).build().unwrap();
+
+        assert!(!ctx.restore_state(&path).unwrap());
+        assert!(ctx.get_chain(NEUTRON_CHAIN_NAME).contract_codes.is_empty());
+        assert!(ctx.channels.is_empty());
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_restore_state_keeps_existing_entries() {
+        let mock = MockLocalIc::start().unwrap();
+        let path = state_file("keeps_existing");
+        context_with_state(&mock).save_state(&path).unwrap();
+
+        let mut ctx = mock_context_builder(&mock)
+            .with_transfer_channel_id(NEUTRON_CHAIN_NAME, OSMOSIS_CHAIN_NAME, "channel-7")
+            .build()
+            .unwrap();
+        ctx.get_mut_chain(NEUTRON_CHAIN_NAME)
+            .contract_codes
+            .insert(String::from("factory"), 2);
+
+        assert!(ctx.restore_state(&path).unwrap());
+
+        let neutron = ctx.get_chain(NEUTRON_CHAIN_NAME);
+        assert_eq!(neutron.contract_codes.get("factory"), Some(&2));
+        assert_eq!(
+            neutron.contract_addrs.get("factory").map(String::as_str),
+            Some("neutron1factory")
+        );
+        assert_eq!(
+            ctx.channels
+                .get(&(
+                    NEUTRON_CHAIN_NAME.to_owned(),
+                    OSMOSIS_CHAIN_NAME.to_owned(),
+                    TRANSFER_PORT.to_owned()
+                ))
+                .map(|channel| channel.channel_id.as_str()),
+            Some("channel-7")
+        );
+        assert_eq!(
+            ctx.get_connections()
+                .src(NEUTRON_CHAIN_NAME)
+                .dest(OSMOSIS_CHAIN_NAME)
+                .get(),
+            "connection-0"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
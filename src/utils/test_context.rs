@@ -2,9 +2,11 @@ use super::super::{
     error::Error,
     types::{
         config::{ConfigChain, Logs},
-        ibc::Channel as QueryChannel,
+        ibc::{Channel as QueryChannel, ChannelInfo},
+        registry::ChainRegistry,
+        tx::TxOptions,
     },
-    ICTEST_HOME_VAR, LOCAL_IC_API_URL, TRANSFER_PORT,
+    CCV_CONSUMER_PORT, CCV_PROVIDER_PORT, ICTEST_HOME_VAR, LOCAL_IC_API_URL, TRANSFER_PORT,
 };
 
 use localic_std::{
@@ -13,14 +15,15 @@ use localic_std::{
     relayer::{Channel, Relayer},
     transactions::ChainRequestBuilder,
 };
-use std::{collections::HashMap, env, fs::OpenOptions, path::PathBuf};
+use log::warn;
+use std::{collections::HashMap, env, fs::File, io::BufReader, path::PathBuf};
 
 /// A configurable builder that can be used to create a TestContext.
+#[derive(Clone)]
 pub struct TestContextBuilder {
     chains: Vec<ConfigChain>,
     api_url: Option<String>,
-    transfer_channel_ids: HashMap<(String, String), String>,
-    ccv_channel_ids: HashMap<(String, String), String>,
+    channels: HashMap<(String, String, String), ChannelInfo>,
     connection_ids: HashMap<(String, String), String>,
     ibc_denoms: HashMap<(String, String), String>,
     artifacts_dir: Option<String>,
@@ -28,6 +31,9 @@ pub struct TestContextBuilder {
     transfer_channels: Vec<(String, String)>,
     ccv_channels: Vec<(String, String)>,
     log_file_path: Option<String>,
+    state_file_path: Option<String>,
+    logs_from_api: bool,
+    logs: Option<Logs>,
 }
 
 impl Default for TestContextBuilder {
@@ -35,8 +41,7 @@ impl Default for TestContextBuilder {
         Self {
             chains: Default::default(),
             api_url: Some(LOCAL_IC_API_URL.to_owned()),
-            transfer_channel_ids: Default::default(),
-            ccv_channel_ids: Default::default(),
+            channels: Default::default(),
             connection_ids: Default::default(),
             ibc_denoms: Default::default(),
             artifacts_dir: Default::default(),
@@ -44,11 +49,53 @@ impl Default for TestContextBuilder {
             transfer_channels: Default::default(),
             ccv_channels: Default::default(),
             log_file_path: Default::default(),
+            state_file_path: Default::default(),
+            logs_from_api: Default::default(),
+            logs: Default::default(),
         }
     }
 }
 
 impl TestContextBuilder {
+    /// Creates a builder for every chain in a local-ic logs file with a chain ID
+    /// bundled with localic-utils. Transfer channels and connections between the
+    /// context's chains are read from the logs when the context is built, instead
+    /// of being queried from the relayer.
+    pub fn from_logs(logs: Logs) -> Self {
+        Self::from_logs_with_registry(logs, &ChainRegistry::builtin())
+    }
+
+    /// Creates a builder for every chain in a local-ic logs file with a chain ID in the registry.
+    pub fn from_logs_with_registry(logs: Logs, registry: &ChainRegistry) -> Self {
+        let chains = logs
+            .chains
+            .iter()
+            .filter_map(|entry| {
+                let chain = registry.get_by_chain_id(&entry.chain_id).cloned();
+
+                if chain.is_none() {
+                    warn!(
+                        "No registered config for chain {}, it must be added with with_chain",
+                        entry.chain_id
+                    );
+                }
+
+                chain
+            })
+            .collect();
+
+        Self {
+            chains,
+            logs: Some(logs),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a builder for every chain in a config/logs.json file with a known chain ID.
+    pub fn from_log_file(path: &str) -> Result<Self, Error> {
+        Ok(Self::from_logs(read_log_file(path)?))
+    }
+
     /// Resets the chains that this builder will create a context for to the specified value.
     pub fn with_chains(&mut self, chains: impl Into<Vec<ConfigChain>>) -> &mut Self {
         self.chains = chains.into();
@@ -70,12 +117,41 @@ impl TestContextBuilder {
         self
     }
 
+    /// Sets the channel map, keyed by (src_chain, dest_chain, src_port), to the specified map.
+    pub fn with_channels(
+        &mut self,
+        channels: impl Into<HashMap<(String, String, String), ChannelInfo>>,
+    ) -> &mut Self {
+        self.channels = channels.into();
+
+        self
+    }
+
+    /// Inserts a given channel for a chain pair and port into the builder.
+    pub fn with_channel(
+        &mut self,
+        chain_a: impl Into<String>,
+        chain_b: impl Into<String>,
+        port: impl Into<String>,
+        channel: ChannelInfo,
+    ) -> &mut Self {
+        self.channels
+            .insert((chain_a.into(), chain_b.into(), port.into()), channel);
+
+        self
+    }
+
     /// Sets the transfer channel ID map to the specified map.
     pub fn with_transfer_channel_ids(
         &mut self,
         ids: impl Into<HashMap<(String, String), String>>,
     ) -> &mut Self {
-        self.transfer_channel_ids = ids.into();
+        self.channels
+            .retain(|(_, _, port), _| port != TRANSFER_PORT);
+
+        for ((chain_a, chain_b), channel_id) in ids.into() {
+            self.with_transfer_channel_id(chain_a, chain_b, channel_id);
+        }
 
         self
     }
@@ -87,10 +163,15 @@ impl TestContextBuilder {
         chain_b: impl Into<String>,
         channel_id: impl Into<String>,
     ) -> &mut Self {
-        self.transfer_channel_ids
-            .insert((chain_a.into(), chain_b.into()), channel_id.into());
-
-        self
+        self.with_channel(
+            chain_a,
+            chain_b,
+            TRANSFER_PORT,
+            ChannelInfo {
+                counterparty_port_id: TRANSFER_PORT.to_owned(),
+                ..ChannelInfo::from_id(channel_id)
+            },
+        )
     }
 
     /// Inserts a transfer channel between the specified chains in both directions.
@@ -121,27 +202,47 @@ impl TestContextBuilder {
         self
     }
 
-    /// Sets the transfer channel ID map to the specified map.
+    /// Sets the ccv channel ID map, keyed by (src_chain, dest_chain, src_port), to the specified map.
     pub fn with_ccv_channel_ids(
         &mut self,
-        ids: impl Into<HashMap<(String, String), String>>,
+        ids: impl Into<HashMap<(String, String, String), String>>,
     ) -> &mut Self {
-        self.ccv_channel_ids = ids.into();
+        self.channels
+            .retain(|(_, _, port), _| port != CCV_PROVIDER_PORT && port != CCV_CONSUMER_PORT);
+
+        for ((chain_a, chain_b, port), channel_id) in ids.into() {
+            self.with_ccv_channel_id(chain_a, chain_b, port, channel_id);
+        }
 
         self
     }
 
-    /// Inserts a given channel ID for a chain pair into the builder.
+    /// Inserts a given channel ID for a chain pair into the builder, on chain_a's end of
+    /// the channel. The port is the provider port if chain_a is the provider, and the
+    /// consumer port otherwise.
     pub fn with_ccv_channel_id(
         &mut self,
         chain_a: impl Into<String>,
         chain_b: impl Into<String>,
+        port: impl Into<String>,
         channel_id: impl Into<String>,
     ) -> &mut Self {
-        self.ccv_channel_ids
-            .insert((chain_a.into(), chain_b.into()), channel_id.into());
-
-        self
+        let port = port.into();
+        let counterparty_port = if port == CCV_PROVIDER_PORT {
+            CCV_CONSUMER_PORT
+        } else {
+            CCV_PROVIDER_PORT
+        };
+
+        self.with_channel(
+            chain_a,
+            chain_b,
+            port,
+            ChannelInfo {
+                counterparty_port_id: counterparty_port.to_owned(),
+                ..ChannelInfo::from_id(channel_id)
+            },
+        )
     }
 
     /// Sets the connection ID map to the specified map.
@@ -211,12 +312,26 @@ impl TestContextBuilder {
         self
     }
 
+    /// Fetches chain and channel info from the local-ic API if the logs file cannot be read.
+    pub fn with_logs_from_api(&mut self, logs_from_api: bool) -> &mut Self {
+        self.logs_from_api = logs_from_api;
+
+        self
+    }
+
+    /// Sets the path to a state file written by `TestContext::save_state`. If the file
+    /// was recorded in the current local-ic session, the built context is restored from it.
+    pub fn with_state_file(&mut self, path: impl Into<String>) -> &mut Self {
+        self.state_file_path = Some(path.into());
+
+        self
+    }
+
     /// Builds a TestContext from the specified options.
     pub fn build(&self) -> Result<TestContext, Error> {
         let TestContextBuilder {
             chains,
-            transfer_channel_ids,
-            ccv_channel_ids,
+            channels,
             connection_ids,
             ibc_denoms,
             api_url,
@@ -225,6 +340,9 @@ impl TestContextBuilder {
             transfer_channels,
             ccv_channels,
             log_file_path,
+            state_file_path,
+            logs_from_api,
+            logs,
         } = self;
 
         // Upload contract artifacts
@@ -239,14 +357,17 @@ impl TestContextBuilder {
             let relayer = Relayer::new(&rb);
             let channels = relayer.get_channels(&rb.chain_id)?;
 
-            Ok(LocalChain::new(
+            let mut chain = LocalChain::new(
                 rb,
                 c.admin_addr,
                 c.denom,
                 channels,
                 c.chain_name,
                 c.chain_prefix,
-            ))
+            );
+            chain.tx_options = c.tx_options;
+
+            Ok(chain)
         }
 
         let chains_res: Result<HashMap<String, LocalChain>, Error> = chains
@@ -270,9 +391,52 @@ impl TestContextBuilder {
             });
         let chains = chains_res?;
 
-        let mut transfer_channel_ids = transfer_channel_ids.clone();
+        let log_file = if let Some(logs) = logs {
+            logs.clone()
+        } else {
+            let log_file_path = log_file_path.clone().unwrap_or_else(|| {
+                format!(
+                    "{}configs/logs.json",
+                    env::var(ICTEST_HOME_VAR)
+                        .map(|path| if path.ends_with("/") {
+                            path
+                        } else {
+                            format!("{path}/")
+                        })
+                        .unwrap_or_default()
+                )
+            });
+            match read_log_file(&log_file_path) {
+                Ok(log_file) => log_file,
+                Err(e) if *logs_from_api => {
+                    warn!("{e}, fetching logs from the local-ic API");
+
+                    fetch_log_file(
+                        api_url
+                            .as_deref()
+                            .ok_or(Error::MissingBuilderParam(String::from("api_url")))?,
+                    )?
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        let mut channels = channels.clone();
         let mut connection_ids = connection_ids.clone();
 
+        // Channels are only discovered from logs passed in explicitly
+        if logs.is_some() {
+            for ((src, dest, port), channel) in find_logs_channels(&log_file, &chains) {
+                if port == TRANSFER_PORT {
+                    connection_ids
+                        .entry((src.clone(), dest.clone()))
+                        .or_insert(channel.connection_id.clone());
+                }
+
+                channels.entry((src, dest, port)).or_insert(channel);
+            }
+        }
+
         for (chain_a, chain_b) in transfer_channels {
             let chain_a_chain = chains
                 .get(chain_a)
@@ -287,8 +451,10 @@ impl TestContextBuilder {
                 &chain_b_chain.rb.chain_id,
             )?;
 
-            transfer_channel_ids
-                .insert((chain_a.clone(), chain_b.clone()), conns.channel_id.clone());
+            channels.insert(
+                (chain_a.clone(), chain_b.clone(), TRANSFER_PORT.to_owned()),
+                conns.channel,
+            );
             connection_ids.insert((chain_a.clone(), chain_b.clone()), conns.connection_id);
 
             let conns = find_pairwise_transfer_channel_ids(
@@ -297,13 +463,13 @@ impl TestContextBuilder {
                 &chain_a_chain.rb.chain_id,
             )?;
 
-            transfer_channel_ids
-                .insert((chain_b.clone(), chain_a.clone()), conns.channel_id.clone());
+            channels.insert(
+                (chain_b.clone(), chain_a.clone(), TRANSFER_PORT.to_owned()),
+                conns.channel,
+            );
             connection_ids.insert((chain_b.clone(), chain_a.clone()), conns.connection_id);
         }
 
-        let mut ccv_channel_ids = ccv_channel_ids.clone();
-
         for (chain_a, chain_b) in ccv_channels {
             let chain_a_chain = chains
                 .get(chain_a)
@@ -315,37 +481,27 @@ impl TestContextBuilder {
             let conns =
                 find_pairwise_ccv_channel_ids(&chain_a_chain.channels, &chain_b_chain.channels)?;
 
-            ccv_channel_ids.insert(
-                (chain_a.clone(), chain_b.clone()),
-                conns.0.channel_id.clone(),
+            channels.insert(
+                (
+                    chain_a.clone(),
+                    chain_b.clone(),
+                    CCV_PROVIDER_PORT.to_owned(),
+                ),
+                conns.0.channel,
             );
-            ccv_channel_ids.insert(
-                (chain_b.clone(), chain_a.clone()),
-                conns.1.channel_id.clone(),
+            channels.insert(
+                (
+                    chain_b.clone(),
+                    chain_a.clone(),
+                    CCV_CONSUMER_PORT.to_owned(),
+                ),
+                conns.1.channel,
             );
         }
 
-        let log_f = OpenOptions::new()
-            .read(true)
-            .open(log_file_path.clone().unwrap_or_else(|| {
-                format!(
-                    "{}configs/logs.json",
-                    env::var(ICTEST_HOME_VAR)
-                        .map(|path| if path.ends_with("/") {
-                            path
-                        } else {
-                            format!("{path}/")
-                        })
-                        .unwrap_or_default()
-                )
-            }))
-            .unwrap();
-        let log_file: Logs = serde_json::from_reader(&log_f).unwrap();
-
-        Ok(TestContext {
+        let mut ctx = TestContext {
             chains,
-            transfer_channel_ids,
-            ccv_channel_ids: ccv_channel_ids.clone(),
+            channels,
             connection_ids: connection_ids.clone(),
             ibc_denoms: ibc_denoms.clone(),
             artifacts_dir: artifacts_dir
@@ -353,16 +509,22 @@ impl TestContextBuilder {
                 .ok_or(Error::MissingBuilderParam(String::from("artifacts_dir")))?,
             unwrap_logs: *unwrap_raw_logs,
             log_file,
-        })
+        };
+
+        if let Some(path) = state_file_path {
+            ctx.restore_state(path)?;
+        }
+
+        Ok(ctx)
     }
 }
 
 pub struct TestContext {
     pub chains: HashMap<String, LocalChain>,
-    // maps (src_chain_id, dest_chain_id) to transfer channel id
-    pub transfer_channel_ids: HashMap<(String, String), String>,
-    // maps (src_chain_id, dest_chain_id) to ccv channel id
-    pub ccv_channel_ids: HashMap<(String, String), String>,
+    // maps (src_chain_id, dest_chain_id, src_port) to the src chain's end of
+    // the channel. Transfer channels are on the transfer port, and ccv channels
+    // are on the provider or consumer port.
+    pub channels: HashMap<(String, String, String), ChannelInfo>,
     // maps (src_chain_id, dest_chain_id) to connection id
     pub connection_ids: HashMap<(String, String), String>,
     // maps (src_chain_id, dest_chain_id) to src chain native
@@ -378,6 +540,38 @@ pub struct TestContext {
     pub log_file: Logs,
 }
 
+impl TestContext {
+    /// Re-reads the outgoing channels of every chain from the relayer, picking up
+    /// channels opened after the context was built.
+    pub fn refresh_channels(&mut self) -> Result<(), Error> {
+        for chain in self.chains.values_mut() {
+            chain.refresh_channels()?;
+        }
+
+        Ok(())
+    }
+
+    /// Maps (src_chain_id, dest_chain_id) to the ID of the src chain's end of their transfer channel.
+    pub fn transfer_channel_ids(&self) -> HashMap<(String, String), String> {
+        self.channel_ids_on_ports(&[TRANSFER_PORT])
+    }
+
+    /// Maps (src_chain_id, dest_chain_id) to the ID of the src chain's end of their ccv channel.
+    pub fn ccv_channel_ids(&self) -> HashMap<(String, String), String> {
+        self.channel_ids_on_ports(&[CCV_PROVIDER_PORT, CCV_CONSUMER_PORT])
+    }
+
+    fn channel_ids_on_ports(&self, ports: &[&str]) -> HashMap<(String, String), String> {
+        self.channels
+            .iter()
+            .filter(|((_, _, port), _)| ports.contains(&port.as_str()))
+            .map(|((src, dest, _), channel)| {
+                ((src.clone(), dest.clone()), channel.channel_id.clone())
+            })
+            .collect()
+    }
+}
+
 pub struct LocalChain {
     /// ChainRequestBuilder
     pub rb: ChainRequestBuilder,
@@ -388,12 +582,16 @@ pub struct LocalChain {
     /// outgoing connection ids available (dest_chain_id -> connection_id)
     pub connection_ids: HashMap<String, String>,
     pub admin_addr: String,
+    /// addresses of keys created or imported by the context (key -> address)
+    pub(crate) key_addrs: HashMap<String, String>,
     pub native_denom: String,
     /// contract address for the deployed instance of a contract
     pub contract_addrs: HashMap<String, String>,
     /// The name of the chain
     pub chain_name: String,
     pub chain_prefix: String,
+    /// The default gas, fees, and flags of transactions sent on the chain
+    pub tx_options: TxOptions,
 }
 
 impl LocalChain {
@@ -411,10 +609,12 @@ impl LocalChain {
             channels,
             connection_ids: Default::default(),
             admin_addr,
+            key_addrs: Default::default(),
             native_denom,
             contract_addrs: Default::default(),
             chain_name,
             chain_prefix,
+            tx_options: Default::default(),
         }
     }
 
@@ -427,6 +627,22 @@ impl LocalChain {
         self.contract_codes.insert(id.to_string(), code);
     }
 
+    /// Formats the gas, fee, and other flags of a tx sent on this chain. Options set on the tx's
+    /// builder take precedence over the chain's options, which take precedence over the tx's defaults.
+    pub fn tx_flags(&self, options: &TxOptions, defaults: &TxOptions) -> Result<String, Error> {
+        options
+            .or(&self.tx_options)
+            .or(defaults)
+            .to_flags(&self.native_denom)
+    }
+
+    /// Re-reads the chain's outgoing channels from the relayer.
+    pub fn refresh_channels(&mut self) -> Result<(), Error> {
+        self.channels = Relayer::new(&self.rb).get_channels(&self.rb.chain_id)?;
+
+        Ok(())
+    }
+
     pub fn wait_for_blocks(&self, blocks: u64) {
         let chain = Chain::new(&self.rb);
         let current_height = chain.get_height();
@@ -437,6 +653,112 @@ impl LocalChain {
     }
 }
 
+/// Reads the config/logs.json file written by local-ic.
+pub fn read_log_file(path: &str) -> Result<Logs, Error> {
+    let log_f = File::open(path).map_err(|e| Error::LogFile {
+        path: path.to_owned(),
+        source: Box::new(e),
+    })?;
+
+    serde_json::from_reader(BufReader::new(log_f)).map_err(|e| Error::LogFile {
+        path: path.to_owned(),
+        source: Box::new(e),
+    })
+}
+
+/// Fetches the contents of config/logs.json from the local-ic API.
+pub fn fetch_log_file(api_url: &str) -> Result<Logs, Error> {
+    let url = format!("{}/info?request=logs", api_url.trim_end_matches('/'));
+
+    reqwest::blocking::get(&url)
+        .and_then(|resp| resp.error_for_status())
+        .and_then(|resp| resp.json())
+        .map_err(|e| Error::LogFile {
+            path: url,
+            source: Box::new(e),
+        })
+}
+
+/// Pairs the open channels listed in local-ic logs between the given chains.
+/// Maps (src_chain_name, dest_chain_name, src_port) to the src chain's end of the
+/// channel. Channels whose counterparty cannot be unambiguously identified are skipped.
+pub fn find_logs_channels(
+    logs: &Logs,
+    chains: &HashMap<String, LocalChain>,
+) -> HashMap<(String, String, String), ChannelInfo> {
+    let chain_names: HashMap<&str, &str> = chains
+        .values()
+        .map(|chain| (chain.rb.chain_id.as_str(), chain.chain_name.as_str()))
+        .collect();
+    let ibc_paths: HashMap<&str, &[String]> = logs
+        .chains
+        .iter()
+        .map(|chain| (chain.chain_id.as_str(), chain.ibc_paths.as_slice()))
+        .collect();
+
+    // Chains whose paths are known can only be linked by a shared path
+    let shares_path =
+        |chain_a: &str, chain_b: &str| match (ibc_paths.get(chain_a), ibc_paths.get(chain_b)) {
+            (Some(paths_a), Some(paths_b)) if !paths_a.is_empty() && !paths_b.is_empty() => {
+                paths_a.iter().any(|path| paths_b.contains(path))
+            }
+            _ => true,
+        };
+
+    let open_channels = || {
+        logs.ibc_channels
+            .iter()
+            .filter(|entry| entry.channel.state == "STATE_OPEN")
+    };
+
+    let mut channels = HashMap::new();
+
+    for entry in open_channels() {
+        let Some(src_chain) = chain_names.get(entry.chain_id.as_str()) else {
+            continue;
+        };
+
+        let counterparties = open_channels()
+            .filter(|other| {
+                other.chain_id != entry.chain_id
+                    && other.channel.channel_id == entry.channel.counterparty.channel_id
+                    && other.channel.port_id == entry.channel.counterparty.port_id
+                    && other.channel.counterparty.channel_id == entry.channel.channel_id
+                    && other.channel.counterparty.port_id == entry.channel.port_id
+                    && shares_path(&entry.chain_id, &other.chain_id)
+            })
+            .collect::<Vec<_>>();
+
+        let [counterparty] = counterparties.as_slice() else {
+            if counterparties.len() > 1 {
+                warn!(
+                    "Ambiguous counterparty for {} on {}, skipping",
+                    entry.channel.channel_id, entry.chain_id
+                );
+            }
+
+            continue;
+        };
+
+        let Some(dest_chain) = chain_names.get(counterparty.chain_id.as_str()) else {
+            continue;
+        };
+        if entry.channel.connection_hops.is_empty() {
+            continue;
+        }
+
+        channels
+            .entry((
+                src_chain.to_string(),
+                dest_chain.to_string(),
+                entry.channel.port_id.clone(),
+            ))
+            .or_insert_with(|| ChannelInfo::from(&entry.channel));
+    }
+
+    channels
+}
+
 pub fn find_pairwise_transfer_channel_ids(
     rb: &ChainRequestBuilder,
     src_chain_id: &str,
@@ -458,6 +780,7 @@ pub fn find_pairwise_transfer_channel_ids(
                 index: 0,
                 channel_id: channel.channel_id.to_string(),
                 connection_id: channel.connection_hops[0].to_string(),
+                channel: ChannelInfo::from(&channel),
             };
             return Ok(party_channel);
         }
@@ -468,6 +791,8 @@ pub fn find_pairwise_transfer_channel_ids(
     )))
 }
 
+/// Finds the open CCV channel between a provider and a consumer chain. Channels whose
+/// handshake has not completed on both ends are skipped.
 pub fn find_pairwise_ccv_channel_ids(
     provider_channels: &[Channel],
     consumer_channels: &[Channel],
@@ -480,16 +805,20 @@ pub fn find_pairwise_ccv_channel_ids(
                 && b_chan.port_id == "consumer"
                 && a_chan.ordering == "ORDER_ORDERED"
                 && b_chan.ordering == "ORDER_ORDERED"
+                && a_chan.state == "STATE_OPEN"
+                && b_chan.state == "STATE_OPEN"
             {
                 let provider_channel_result = PairwiseChannelResult {
                     index: a_i,
                     channel_id: a_chan.channel_id.to_string(),
                     connection_id: a_chan.connection_hops[0].to_string(),
+                    channel: ChannelInfo::from(a_chan),
                 };
                 let consumer_channel_result = PairwiseChannelResult {
                     index: b_i,
                     channel_id: b_chan.channel_id.to_string(),
                     connection_id: b_chan.connection_hops[0].to_string(),
+                    channel: ChannelInfo::from(b_chan),
                 };
                 return Ok((provider_channel_result, consumer_channel_result));
             }
@@ -504,4 +833,63 @@ pub struct PairwiseChannelResult {
     pub index: usize,
     pub channel_id: String,
     pub connection_id: String,
+    pub channel: ChannelInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::{testing::MockLocalIc, NEUTRON_CHAIN_ID, NEUTRON_CHAIN_NAME},
+        *,
+    };
+    use crate::ConfigChainBuilder;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_fetches_logs_from_api() {
+        let mock = MockLocalIc::start().unwrap();
+        mock.on_logs(json!({
+            "start_time": 1721000000,
+            "chains": [{
+                "chain_id": NEUTRON_CHAIN_ID,
+                "chain_name": "neutron",
+                "rpc_address": "http://localhost:26657",
+                "grpc_address": "localhost:9090",
+                "p2p_address": "localhost:26656",
+                "ibc_paths": [],
+            }],
+            "ibc_channels": [],
+        }));
+
+        let ctx = TestContextBuilder::default()
+            .with_api_url(mock.api_url())
+            .with_artifacts_dir("contracts")
+            .with_log_file_path("/nonexistent/logs.json")
+            .with_logs_from_api(true)
+            .with_chain(ConfigChainBuilder::default_neutron().build().unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(ctx.log_file.start_time, 1721000000);
+        assert_eq!(ctx.log_file.chains[0].chain_id, NEUTRON_CHAIN_ID);
+        assert!(ctx.chains.contains_key(NEUTRON_CHAIN_NAME));
+        assert!(mock
+            .requests()
+            .iter()
+            .any(|req| req.method == "GET" && req.path == "/info"));
+    }
+
+    #[test]
+    fn test_build_fails_without_logs() {
+        let mock = MockLocalIc::start().unwrap();
+
+        let res = TestContextBuilder::default()
+            .with_api_url(mock.api_url())
+            .with_artifacts_dir("contracts")
+            .with_log_file_path("/nonexistent/logs.json")
+            .with_chain(ConfigChainBuilder::default_neutron().build().unwrap())
+            .build();
+
+        assert!(matches!(res, Err(Error::LogFile { .. })));
+    }
 }